@@ -0,0 +1,81 @@
+#[cfg(feature = "bindgen")]
+extern crate bindgen;
+#[cfg(not(feature = "system-lzo2"))]
+extern crate cc;
+#[cfg(feature = "system-lzo2")]
+extern crate pkg_config;
+
+#[cfg(not(feature = "system-lzo2"))]
+use std::path::Path;
+use std::path::PathBuf;
+
+fn main() {
+    println!("cargo:rustc-check-cfg=cfg(minilzo_vendored)");
+    let (include, header) = link();
+
+    #[cfg(feature = "bindgen")]
+    generate_bindings(&include, header);
+    #[cfg(not(feature = "bindgen"))]
+    let _ = (include, header);
+}
+
+/// Link the system lzo2.
+///
+/// Returns the include paths and the header to generate bindings from.
+#[cfg(feature = "system-lzo2")]
+fn link() -> (Vec<PathBuf>, &'static str) {
+    if let Ok(lib) = pkg_config::probe_library("lzo2") {
+        return (lib.include_paths, "lzo1x.h");
+    }
+
+    println!("cargo:rustc-link-lib=lzo2");
+    (vec![PathBuf::from("/usr/include/lzo")], "lzo1x.h")
+}
+
+/// Compile the bundled miniLZO.
+///
+/// Its sources are required, there is no fallback to the system lzo2.
+#[cfg(not(feature = "system-lzo2"))]
+fn link() -> (Vec<PathBuf>, &'static str) {
+    let src = Path::new("minilzo");
+    println!("cargo:rerun-if-changed=minilzo");
+
+    if !src.join("minilzo.c").exists() {
+        panic!("miniLZO sources not found in {}, see {}/README.md or enable the `system-lzo2` feature",
+               src.display(), src.display());
+    }
+
+    cc::Build::new()
+        .file(src.join("minilzo.c"))
+        .include(src)
+        .warnings(false)
+        .compile("minilzo");
+    println!("cargo:include={}", src.display());
+    println!("cargo:rustc-cfg=minilzo_vendored");
+
+    (vec![src.to_path_buf()], "minilzo.h")
+}
+
+/// Generate bindings for the functions minilzo provides.
+///
+/// `lzo_uint` and `lzo_int` are left out, `src/lib.rs` defines them as
+/// `usize` and `isize` for every target.
+#[cfg(feature = "bindgen")]
+fn generate_bindings(include: &[PathBuf], header: &str) {
+    let out = PathBuf::from(std::env::var("OUT_DIR").unwrap());
+
+    let bindings = bindgen::Builder::default()
+        .header_contents("wrapper.h", &format!("#include <{}>", header))
+        .clang_args(include.iter().map(|p| format!("-I{}", p.display())))
+        .ctypes_prefix("::libc")
+        .allowlist_function("lzo_.*|_lzo_.*|__lzo_.*|lzo1x_1_compress|lzo1x_decompress(_safe)?")
+        .allowlist_type("lzo_.*")
+        .blocklist_type("lzo_u?int")
+        .blocklist_item("lzo_cta__.*")
+        .generate()
+        .expect("unable to generate minilzo bindings");
+
+    bindings
+        .write_to_file(out.join("minilzo.rs"))
+        .expect("unable to write minilzo bindings");
+}
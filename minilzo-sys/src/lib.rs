@@ -1,11 +1,59 @@
 extern crate libc;
 
+use std::mem::size_of;
+
+use libc::{c_int, c_long, c_short};
+
+#[cfg(not(feature = "bindgen"))]
 #[allow(non_camel_case_types)]
 mod minilzo;
 
+#[cfg(feature = "bindgen")]
+#[allow(non_camel_case_types, non_upper_case_globals, non_snake_case, dead_code)]
+mod minilzo {
+    pub type lzo_uint = usize;
+    pub type lzo_int = isize;
+
+    include!(concat!(env!("OUT_DIR"), "/minilzo.rs"));
+}
+
 /* Manually added */
-pub const LZO1X_1_MEM_COMPRESS : usize = (16384 * 8);
+pub const LZO1X_1_MEM_COMPRESS : usize = 16384 * 8;
 pub const LZO1X_MEM_COMPRESS : usize = LZO1X_1_MEM_COMPRESS;
+pub const LZO1X_1_11_MEM_COMPRESS : usize = 2048 * 8;
+pub const LZO1X_1_12_MEM_COMPRESS : usize = 4096 * 8;
+pub const LZO1X_1_15_MEM_COMPRESS : usize = 32768 * 8;
+pub const LZO1X_999_MEM_COMPRESS : usize = 14 * 16384 * 2;
+pub const LZO1X_MEM_DECOMPRESS : usize = 0;
+
+/* Manually added, the work memory sizes from lzo1.h to lzo2a.h */
+pub const LZO1_MEM_COMPRESS : usize = 8192 * 8;
+pub const LZO1_99_MEM_COMPRESS : usize = 65536 * 8;
+pub const LZO1_MEM_DECOMPRESS : usize = 0;
+pub const LZO1A_MEM_COMPRESS : usize = 8192 * 8;
+pub const LZO1A_99_MEM_COMPRESS : usize = 65536 * 8;
+pub const LZO1A_MEM_DECOMPRESS : usize = 0;
+pub const LZO1B_MEM_COMPRESS : usize = 16384 * 8;
+pub const LZO1B_99_MEM_COMPRESS : usize = 65536 * 8;
+pub const LZO1B_999_MEM_COMPRESS : usize = 3 * 65536 * 8;
+pub const LZO1B_MEM_DECOMPRESS : usize = 0;
+pub const LZO1C_MEM_COMPRESS : usize = 16384 * 8;
+pub const LZO1C_99_MEM_COMPRESS : usize = 65536 * 8;
+pub const LZO1C_999_MEM_COMPRESS : usize = 5 * 16384 * 2;
+pub const LZO1C_MEM_DECOMPRESS : usize = 0;
+pub const LZO1F_MEM_COMPRESS : usize = 16384 * 8;
+pub const LZO1F_999_MEM_COMPRESS : usize = 5 * 16384 * 2;
+pub const LZO1F_MEM_DECOMPRESS : usize = 0;
+pub const LZO1Y_MEM_COMPRESS : usize = 16384 * 8;
+pub const LZO1Y_999_MEM_COMPRESS : usize = 14 * 16384 * 2;
+pub const LZO1Y_MEM_DECOMPRESS : usize = 0;
+pub const LZO1Z_999_MEM_COMPRESS : usize = 14 * 16384 * 2;
+pub const LZO1Z_MEM_DECOMPRESS : usize = 0;
+pub const LZO2A_999_MEM_COMPRESS : usize = 8 * 16384 * 2;
+pub const LZO2A_MEM_DECOMPRESS : usize = 0;
+
+/// Whether the bundled miniLZO is linked, as opposed to the system liblzo2.
+pub const VENDORED : bool = cfg!(minilzo_vendored);
 
 pub const LZO_E_OK : i32                 = 0;
 pub const LZO_E_ERROR : i32              = -1;
@@ -23,3 +71,170 @@ pub const LZO_E_OUTPUT_NOT_CONSUMED: i32 = -12;
 pub const LZO_E_INTERNAL_ERROR: i32      = -99;
 
 pub use minilzo::*;
+
+/* Manually added, the lzo_init() macro from lzoconf.h */
+/// Initialize the library, passing the type sizes Rust uses to be checked against the C library.
+///
+/// # Safety
+///
+/// Calls into the C library; safe to call any number of times from any thread.
+pub unsafe fn lzo_init() -> c_int {
+    __lzo_init_v2(lzo_version(),
+                  size_of::<c_short>() as c_int,
+                  size_of::<c_int>() as c_int,
+                  size_of::<c_long>() as c_int,
+                  size_of::<lzo_uint32_t>() as c_int,
+                  size_of::<lzo_uint>() as c_int,
+                  size_of::<lzo_bytep>() as c_int, // lzo_sizeof_dict_t
+                  size_of::<*mut ::libc::c_char>() as c_int,
+                  size_of::<lzo_voidp>() as c_int,
+                  size_of::<lzo_callback_t>() as c_int)
+}
+
+/* Manually added, from lzo1x.h of the full LZO library */
+#[cfg(feature = "system-lzo2")]
+extern "C" {
+    pub fn lzo1x_1_11_compress(src: *const ::libc::c_uchar, src_len: lzo_uint,
+                               dst: *mut ::libc::c_uchar, dst_len: *mut lzo_uint,
+                               wrkmem: *mut ::libc::c_void) -> ::libc::c_int;
+    pub fn lzo1x_1_12_compress(src: *const ::libc::c_uchar, src_len: lzo_uint,
+                               dst: *mut ::libc::c_uchar, dst_len: *mut lzo_uint,
+                               wrkmem: *mut ::libc::c_void) -> ::libc::c_int;
+    pub fn lzo1x_1_15_compress(src: *const ::libc::c_uchar, src_len: lzo_uint,
+                               dst: *mut ::libc::c_uchar, dst_len: *mut lzo_uint,
+                               wrkmem: *mut ::libc::c_void) -> ::libc::c_int;
+    pub fn lzo1x_999_compress(src: *const ::libc::c_uchar, src_len: lzo_uint,
+                              dst: *mut ::libc::c_uchar, dst_len: *mut lzo_uint,
+                              wrkmem: *mut ::libc::c_void) -> ::libc::c_int;
+    pub fn lzo1x_999_compress_level(src: *const ::libc::c_uchar, src_len: lzo_uint,
+                                    dst: *mut ::libc::c_uchar, dst_len: *mut lzo_uint,
+                                    wrkmem: *mut ::libc::c_void,
+                                    dict: *const ::libc::c_uchar, dict_len: lzo_uint,
+                                    cb: *mut lzo_callback_t,
+                                    compression_level: ::libc::c_int) -> ::libc::c_int;
+    pub fn lzo1x_999_compress_dict(src: *const ::libc::c_uchar, src_len: lzo_uint,
+                                   dst: *mut ::libc::c_uchar, dst_len: *mut lzo_uint,
+                                   wrkmem: *mut ::libc::c_void,
+                                   dict: *const ::libc::c_uchar, dict_len: lzo_uint) -> ::libc::c_int;
+    pub fn lzo1x_decompress_dict_safe(src: *const ::libc::c_uchar, src_len: lzo_uint,
+                                      dst: *mut ::libc::c_uchar, dst_len: *mut lzo_uint,
+                                      wrkmem: *mut ::libc::c_void,
+                                      dict: *const ::libc::c_uchar, dict_len: lzo_uint) -> ::libc::c_int;
+    pub fn lzo1x_optimize(src: *mut ::libc::c_uchar, src_len: lzo_uint,
+                          dst: *mut ::libc::c_uchar, dst_len: *mut lzo_uint,
+                          wrkmem: *mut ::libc::c_void) -> ::libc::c_int;
+}
+
+/* Manually added, from lzo1.h to lzo2a.h of the full LZO library */
+#[cfg(feature = "system-lzo2")]
+extern "C" {
+    pub fn lzo1_compress(src: *const ::libc::c_uchar, src_len: lzo_uint,
+                         dst: *mut ::libc::c_uchar, dst_len: *mut lzo_uint,
+                         wrkmem: *mut ::libc::c_void) -> ::libc::c_int;
+    pub fn lzo1_99_compress(src: *const ::libc::c_uchar, src_len: lzo_uint,
+                            dst: *mut ::libc::c_uchar, dst_len: *mut lzo_uint,
+                            wrkmem: *mut ::libc::c_void) -> ::libc::c_int;
+    pub fn lzo1_decompress(src: *const ::libc::c_uchar, src_len: lzo_uint,
+                           dst: *mut ::libc::c_uchar, dst_len: *mut lzo_uint,
+                           wrkmem: *mut ::libc::c_void) -> ::libc::c_int;
+    pub fn lzo1a_compress(src: *const ::libc::c_uchar, src_len: lzo_uint,
+                          dst: *mut ::libc::c_uchar, dst_len: *mut lzo_uint,
+                          wrkmem: *mut ::libc::c_void) -> ::libc::c_int;
+    pub fn lzo1a_99_compress(src: *const ::libc::c_uchar, src_len: lzo_uint,
+                             dst: *mut ::libc::c_uchar, dst_len: *mut lzo_uint,
+                             wrkmem: *mut ::libc::c_void) -> ::libc::c_int;
+    pub fn lzo1a_decompress(src: *const ::libc::c_uchar, src_len: lzo_uint,
+                            dst: *mut ::libc::c_uchar, dst_len: *mut lzo_uint,
+                            wrkmem: *mut ::libc::c_void) -> ::libc::c_int;
+    pub fn lzo1b_1_compress(src: *const ::libc::c_uchar, src_len: lzo_uint,
+                            dst: *mut ::libc::c_uchar, dst_len: *mut lzo_uint,
+                            wrkmem: *mut ::libc::c_void) -> ::libc::c_int;
+    pub fn lzo1b_99_compress(src: *const ::libc::c_uchar, src_len: lzo_uint,
+                             dst: *mut ::libc::c_uchar, dst_len: *mut lzo_uint,
+                             wrkmem: *mut ::libc::c_void) -> ::libc::c_int;
+    pub fn lzo1b_999_compress(src: *const ::libc::c_uchar, src_len: lzo_uint,
+                              dst: *mut ::libc::c_uchar, dst_len: *mut lzo_uint,
+                              wrkmem: *mut ::libc::c_void) -> ::libc::c_int;
+    pub fn lzo1b_decompress(src: *const ::libc::c_uchar, src_len: lzo_uint,
+                            dst: *mut ::libc::c_uchar, dst_len: *mut lzo_uint,
+                            wrkmem: *mut ::libc::c_void) -> ::libc::c_int;
+    pub fn lzo1b_decompress_safe(src: *const ::libc::c_uchar, src_len: lzo_uint,
+                                 dst: *mut ::libc::c_uchar, dst_len: *mut lzo_uint,
+                                 wrkmem: *mut ::libc::c_void) -> ::libc::c_int;
+    pub fn lzo1c_1_compress(src: *const ::libc::c_uchar, src_len: lzo_uint,
+                            dst: *mut ::libc::c_uchar, dst_len: *mut lzo_uint,
+                            wrkmem: *mut ::libc::c_void) -> ::libc::c_int;
+    pub fn lzo1c_99_compress(src: *const ::libc::c_uchar, src_len: lzo_uint,
+                             dst: *mut ::libc::c_uchar, dst_len: *mut lzo_uint,
+                             wrkmem: *mut ::libc::c_void) -> ::libc::c_int;
+    pub fn lzo1c_999_compress(src: *const ::libc::c_uchar, src_len: lzo_uint,
+                              dst: *mut ::libc::c_uchar, dst_len: *mut lzo_uint,
+                              wrkmem: *mut ::libc::c_void) -> ::libc::c_int;
+    pub fn lzo1c_decompress(src: *const ::libc::c_uchar, src_len: lzo_uint,
+                            dst: *mut ::libc::c_uchar, dst_len: *mut lzo_uint,
+                            wrkmem: *mut ::libc::c_void) -> ::libc::c_int;
+    pub fn lzo1c_decompress_safe(src: *const ::libc::c_uchar, src_len: lzo_uint,
+                                 dst: *mut ::libc::c_uchar, dst_len: *mut lzo_uint,
+                                 wrkmem: *mut ::libc::c_void) -> ::libc::c_int;
+    pub fn lzo1f_1_compress(src: *const ::libc::c_uchar, src_len: lzo_uint,
+                            dst: *mut ::libc::c_uchar, dst_len: *mut lzo_uint,
+                            wrkmem: *mut ::libc::c_void) -> ::libc::c_int;
+    pub fn lzo1f_999_compress(src: *const ::libc::c_uchar, src_len: lzo_uint,
+                              dst: *mut ::libc::c_uchar, dst_len: *mut lzo_uint,
+                              wrkmem: *mut ::libc::c_void) -> ::libc::c_int;
+    pub fn lzo1f_decompress(src: *const ::libc::c_uchar, src_len: lzo_uint,
+                            dst: *mut ::libc::c_uchar, dst_len: *mut lzo_uint,
+                            wrkmem: *mut ::libc::c_void) -> ::libc::c_int;
+    pub fn lzo1f_decompress_safe(src: *const ::libc::c_uchar, src_len: lzo_uint,
+                                 dst: *mut ::libc::c_uchar, dst_len: *mut lzo_uint,
+                                 wrkmem: *mut ::libc::c_void) -> ::libc::c_int;
+    pub fn lzo1y_1_compress(src: *const ::libc::c_uchar, src_len: lzo_uint,
+                            dst: *mut ::libc::c_uchar, dst_len: *mut lzo_uint,
+                            wrkmem: *mut ::libc::c_void) -> ::libc::c_int;
+    pub fn lzo1y_999_compress(src: *const ::libc::c_uchar, src_len: lzo_uint,
+                              dst: *mut ::libc::c_uchar, dst_len: *mut lzo_uint,
+                              wrkmem: *mut ::libc::c_void) -> ::libc::c_int;
+    pub fn lzo1y_decompress(src: *const ::libc::c_uchar, src_len: lzo_uint,
+                            dst: *mut ::libc::c_uchar, dst_len: *mut lzo_uint,
+                            wrkmem: *mut ::libc::c_void) -> ::libc::c_int;
+    pub fn lzo1y_decompress_safe(src: *const ::libc::c_uchar, src_len: lzo_uint,
+                                 dst: *mut ::libc::c_uchar, dst_len: *mut lzo_uint,
+                                 wrkmem: *mut ::libc::c_void) -> ::libc::c_int;
+    pub fn lzo1z_999_compress(src: *const ::libc::c_uchar, src_len: lzo_uint,
+                              dst: *mut ::libc::c_uchar, dst_len: *mut lzo_uint,
+                              wrkmem: *mut ::libc::c_void) -> ::libc::c_int;
+    pub fn lzo1z_decompress(src: *const ::libc::c_uchar, src_len: lzo_uint,
+                            dst: *mut ::libc::c_uchar, dst_len: *mut lzo_uint,
+                            wrkmem: *mut ::libc::c_void) -> ::libc::c_int;
+    pub fn lzo1z_decompress_safe(src: *const ::libc::c_uchar, src_len: lzo_uint,
+                                 dst: *mut ::libc::c_uchar, dst_len: *mut lzo_uint,
+                                 wrkmem: *mut ::libc::c_void) -> ::libc::c_int;
+    pub fn lzo2a_999_compress(src: *const ::libc::c_uchar, src_len: lzo_uint,
+                              dst: *mut ::libc::c_uchar, dst_len: *mut lzo_uint,
+                              wrkmem: *mut ::libc::c_void) -> ::libc::c_int;
+    pub fn lzo2a_decompress(src: *const ::libc::c_uchar, src_len: lzo_uint,
+                            dst: *mut ::libc::c_uchar, dst_len: *mut lzo_uint,
+                            wrkmem: *mut ::libc::c_void) -> ::libc::c_int;
+    pub fn lzo2a_decompress_safe(src: *const ::libc::c_uchar, src_len: lzo_uint,
+                                 dst: *mut ::libc::c_uchar, dst_len: *mut lzo_uint,
+                                 wrkmem: *mut ::libc::c_void) -> ::libc::c_int;
+}
+
+#[test]
+fn lzo_init_accepts_type_sizes() {
+    // __lzo_init_v2 compares the sizes Rust passes with the C library's own.
+    assert_eq!(LZO_E_OK, unsafe { lzo_init() });
+}
+
+#[test]
+fn layout_lzo_callback_t() {
+    let ptr = size_of::<usize>();
+    assert_eq!(size_of::<lzo_callback_t>(), 6 * ptr);
+    assert_eq!(std::mem::align_of::<lzo_callback_t>(), std::mem::align_of::<usize>());
+    assert_eq!(std::mem::offset_of!(lzo_callback_t, nalloc), 0);
+    assert_eq!(std::mem::offset_of!(lzo_callback_t, nfree), ptr);
+    assert_eq!(std::mem::offset_of!(lzo_callback_t, nprogress), 2 * ptr);
+    assert_eq!(std::mem::offset_of!(lzo_callback_t, user1), 3 * ptr);
+    assert_eq!(std::mem::offset_of!(lzo_callback_t, user2), 4 * ptr);
+    assert_eq!(std::mem::offset_of!(lzo_callback_t, user3), 5 * ptr);
+}
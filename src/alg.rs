@@ -0,0 +1,298 @@
+//! The other algorithms of the full LZO library.
+//!
+//! Each algorithm has its own bitstream; data has to be decompressed with
+//! the algorithm it was compressed with.
+
+#[cfg(feature = "system-lzo2")]
+use std::mem::size_of;
+
+#[cfg(feature = "system-lzo2")]
+use minilzo_sys::{
+    // Types
+    lzo_compress_t,
+    lzo_decompress_t,
+    lzo_uint,
+
+    // Helpers
+    LZO1_MEM_COMPRESS,
+    LZO1A_MEM_COMPRESS,
+    LZO1B_MEM_COMPRESS,
+    LZO1C_MEM_COMPRESS,
+    LZO1F_MEM_COMPRESS,
+    LZO1X_1_MEM_COMPRESS,
+    LZO1Y_MEM_COMPRESS,
+    LZO1Z_999_MEM_COMPRESS,
+    LZO2A_999_MEM_COMPRESS,
+
+    // (De)compress
+    lzo1_compress,
+    lzo1a_compress,
+    lzo1b_1_compress,
+    lzo1b_decompress_safe,
+    lzo1c_1_compress,
+    lzo1c_decompress_safe,
+    lzo1f_1_compress,
+    lzo1f_decompress_safe,
+    lzo1x_1_compress,
+    lzo1x_decompress_safe,
+    lzo1y_1_compress,
+    lzo1y_decompress_safe,
+    lzo1z_999_compress,
+    lzo1z_decompress_safe,
+    lzo2a_999_compress,
+    lzo2a_decompress_safe,
+};
+
+#[cfg(feature = "system-lzo2")]
+use ensure_init;
+use {Error, compress, compress_bound, decompress};
+
+/// The algorithm families of the LZO library.
+///
+/// Only `Lzo1x` is available without the `system-lzo2` feature; the others
+/// fail with `NotYetImplemented`. The LZO library has no LZO1D and LZO1E.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Algorithm {
+    /// LZO1, compressed with `lzo1_compress`.
+    Lzo1,
+    /// LZO1A, compressed with `lzo1a_compress`.
+    Lzo1a,
+    /// LZO1B, compressed with `lzo1b_1_compress`.
+    Lzo1b,
+    /// LZO1C, compressed with `lzo1c_1_compress`.
+    Lzo1c,
+    /// LZO1F, compressed with `lzo1f_1_compress`.
+    Lzo1f,
+    /// LZO1X, the same as `compress` and `decompress`.
+    Lzo1x,
+    /// LZO1Y, compressed with `lzo1y_1_compress`.
+    Lzo1y,
+    /// LZO1Z, compressed with `lzo1z_999_compress`, the only compressor for it.
+    Lzo1z,
+    /// LZO2A, compressed with `lzo2a_999_compress`, the only compressor for it.
+    Lzo2a,
+}
+
+impl Algorithm {
+    /// All the algorithms.
+    pub const ALL: [Algorithm; 9] = [
+        Algorithm::Lzo1,
+        Algorithm::Lzo1a,
+        Algorithm::Lzo1b,
+        Algorithm::Lzo1c,
+        Algorithm::Lzo1f,
+        Algorithm::Lzo1x,
+        Algorithm::Lzo1y,
+        Algorithm::Lzo1z,
+        Algorithm::Lzo2a,
+    ];
+
+    /// Returns the worst-case size of the output of this algorithm for `len` bytes of input.
+    ///
+    /// The compressors do not check the size of their output buffer, so it
+    /// has to be at least this large.
+    pub fn compress_bound(self, len: usize) -> usize {
+        match self {
+            // LZO2A has the largest expansion, the others stay within the LZO1X bound.
+            Algorithm::Lzo2a => len + len / 8 + 128 + 3,
+            _ => compress_bound(len),
+        }
+    }
+
+    /// The compressor and the size of the work memory it needs.
+    #[cfg(feature = "system-lzo2")]
+    fn compress_fn(self) -> (lzo_compress_t, usize) {
+        match self {
+            Algorithm::Lzo1 => (Some(lzo1_compress), LZO1_MEM_COMPRESS),
+            Algorithm::Lzo1a => (Some(lzo1a_compress), LZO1A_MEM_COMPRESS),
+            Algorithm::Lzo1b => (Some(lzo1b_1_compress), LZO1B_MEM_COMPRESS),
+            Algorithm::Lzo1c => (Some(lzo1c_1_compress), LZO1C_MEM_COMPRESS),
+            Algorithm::Lzo1f => (Some(lzo1f_1_compress), LZO1F_MEM_COMPRESS),
+            Algorithm::Lzo1x => (Some(lzo1x_1_compress), LZO1X_1_MEM_COMPRESS),
+            Algorithm::Lzo1y => (Some(lzo1y_1_compress), LZO1Y_MEM_COMPRESS),
+            Algorithm::Lzo1z => (Some(lzo1z_999_compress), LZO1Z_999_MEM_COMPRESS),
+            Algorithm::Lzo2a => (Some(lzo2a_999_compress), LZO2A_999_MEM_COMPRESS),
+        }
+    }
+
+    /// The decompressor that checks its input. LZO1 and LZO1A have none.
+    #[cfg(feature = "system-lzo2")]
+    fn decompress_fn(self) -> lzo_decompress_t {
+        match self {
+            Algorithm::Lzo1 | Algorithm::Lzo1a => None,
+            Algorithm::Lzo1b => Some(lzo1b_decompress_safe),
+            Algorithm::Lzo1c => Some(lzo1c_decompress_safe),
+            Algorithm::Lzo1f => Some(lzo1f_decompress_safe),
+            Algorithm::Lzo1x => Some(lzo1x_decompress_safe),
+            Algorithm::Lzo1y => Some(lzo1y_decompress_safe),
+            Algorithm::Lzo1z => Some(lzo1z_decompress_safe),
+            Algorithm::Lzo2a => Some(lzo2a_decompress_safe),
+        }
+    }
+}
+
+/// Compress the given data with the chosen algorithm family, if possible.
+/// An error will be returned if compression fails.
+///
+/// As with `compress`, data that does not compress returns `NotCompressible`.
+///
+/// Example
+///
+/// ```rust
+/// use minilzo::Algorithm;
+///
+/// let data = [0; 1024];
+/// let compressed = minilzo::compress_alg(&data[..], Algorithm::Lzo1x).unwrap();
+/// ```
+pub fn compress_alg(indata: &[u8], algorithm: Algorithm) -> Result<Vec<u8>, Error> {
+    if algorithm == Algorithm::Lzo1x {
+        return compress(indata)
+    }
+    compress_other(indata, algorithm)
+}
+
+#[cfg(feature = "system-lzo2")]
+fn compress_other(indata: &[u8], algorithm: Algorithm) -> Result<Vec<u8>, Error> {
+    ensure_init()?;
+    let (compress_fn, wrkmem_size) = algorithm.compress_fn();
+    let compress_fn = compress_fn.unwrap();
+
+    // u64 keeps the work memory aligned as lzo_align_t.
+    let mut wrkmem = vec![0u64; wrkmem_size.div_ceil(size_of::<u64>())];
+
+    let inlen = indata.len();
+    let mut outdata = Vec::with_capacity(algorithm.compress_bound(inlen));
+    let mut outlen = outdata.capacity() as lzo_uint;
+
+    let r = unsafe {
+        compress_fn(
+            indata.as_ptr(),
+            inlen as lzo_uint,
+            outdata.as_mut_ptr(),
+            &mut outlen,
+            wrkmem.as_mut_ptr() as *mut _)
+    };
+
+    if r != 0 {
+        return Err(Error::from_code(r))
+    }
+    let outlen = outlen as usize;
+    if outlen > inlen {
+        return Err(Error::NotCompressible)
+    }
+
+    unsafe { outdata.set_len(outlen) };
+    Ok(outdata)
+}
+
+#[cfg(not(feature = "system-lzo2"))]
+fn compress_other(_indata: &[u8], _algorithm: Algorithm) -> Result<Vec<u8>, Error> {
+    Err(Error::NotYetImplemented)
+}
+
+/// Decompress data of the chosen algorithm family, if possible.
+/// An error will be returned if decompression fails.
+///
+/// `newlen` is the size of the output buffer, as for `decompress`.
+/// The LZO library only has decompressors that trust their input for LZO1
+/// and LZO1A, so they return `NotYetImplemented`.
+///
+/// Example
+///
+/// ```rust
+/// use minilzo::Algorithm;
+///
+/// let data = [0; 1024];
+/// let compressed = minilzo::compress_alg(&data[..], Algorithm::Lzo1x).unwrap();
+/// let decompressed = minilzo::decompress_alg(&compressed, Algorithm::Lzo1x, data.len()).unwrap();
+/// ```
+pub fn decompress_alg(indata: &[u8], algorithm: Algorithm, newlen: usize) -> Result<Vec<u8>, Error> {
+    if algorithm == Algorithm::Lzo1x {
+        return decompress(indata, newlen)
+    }
+    decompress_other(indata, algorithm, newlen)
+}
+
+#[cfg(feature = "system-lzo2")]
+fn decompress_other(indata: &[u8], algorithm: Algorithm, newlen: usize) -> Result<Vec<u8>, Error> {
+    ensure_init()?;
+    let decompress_fn = match algorithm.decompress_fn() {
+        Some(decompress_fn) => decompress_fn,
+        None => return Err(Error::NotYetImplemented),
+    };
+
+    let mut outdata = Vec::with_capacity(newlen);
+    let mut outlen = newlen as lzo_uint;
+
+    let r = unsafe {
+        decompress_fn(
+            indata.as_ptr(),
+            indata.len() as lzo_uint,
+            outdata.as_mut_ptr(),
+            &mut outlen,
+            ::std::ptr::null_mut())
+    };
+
+    if r != 0 {
+        return Err(Error::from_code(r))
+    }
+
+    unsafe { outdata.set_len(outlen as usize) };
+    Ok(outdata)
+}
+
+#[cfg(not(feature = "system-lzo2"))]
+fn decompress_other(_indata: &[u8], _algorithm: Algorithm, _newlen: usize) -> Result<Vec<u8>, Error> {
+    Err(Error::NotYetImplemented)
+}
+
+#[test]
+fn test_lzo1x_round() {
+    let data = ::test_numbers(10_000, 97);
+    let compressed = compress_alg(&data, Algorithm::Lzo1x).unwrap();
+    assert_eq!(compress(&data).unwrap(), compressed);
+    assert_eq!(data, decompress_alg(&compressed, Algorithm::Lzo1x, data.len()).unwrap());
+}
+
+#[cfg(feature = "system-lzo2")]
+#[test]
+fn test_algorithms_round() {
+    let data = ::test_numbers(10_000, 97);
+    for &algorithm in &Algorithm::ALL {
+        let compressed = compress_alg(&data, algorithm).unwrap();
+        assert!(compressed.len() < data.len() / 4, "{:?}", algorithm);
+        if algorithm == Algorithm::Lzo1 || algorithm == Algorithm::Lzo1a {
+            assert_eq!(Err(Error::NotYetImplemented), decompress_alg(&compressed, algorithm, data.len()));
+            continue;
+        }
+        assert_eq!(data, decompress_alg(&compressed, algorithm, data.len()).unwrap(), "{:?}", algorithm);
+        assert_eq!(Err(Error::OutputOverrun), decompress_alg(&compressed, algorithm, data.len() - 1));
+    }
+}
+
+#[cfg(feature = "system-lzo2")]
+#[test]
+fn test_algorithms_incompressible() {
+    let data = ::test_noise(64 * 1024);
+    for &algorithm in &Algorithm::ALL {
+        match compress_alg(&data, algorithm) {
+            Ok(compressed) => assert!(compressed.len() <= data.len(), "{:?}", algorithm),
+            Err(e) => assert_eq!(Error::NotCompressible, e, "{:?}", algorithm),
+        }
+    }
+}
+
+#[test]
+fn test_compress_bound() {
+    for &algorithm in &Algorithm::ALL {
+        assert!(algorithm.compress_bound(1000) >= compress_bound(1000));
+    }
+    assert_eq!(1000 + 125 + 131, Algorithm::Lzo2a.compress_bound(1000));
+}
+
+#[cfg(not(feature = "system-lzo2"))]
+#[test]
+fn test_algorithms_unavailable() {
+    assert_eq!(Err(Error::NotYetImplemented), compress_alg(b"foobar", Algorithm::Lzo1y));
+    assert_eq!(Err(Error::NotYetImplemented), decompress_alg(b"\x11\0\0", Algorithm::Lzo2a, 100));
+}
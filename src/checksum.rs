@@ -0,0 +1,236 @@
+//! The Adler-32 and CRC-32 checksums of the LZO library.
+//!
+//! These are the checksums lzop stores next to its blocks. `lzo_adler32` is
+//! part of miniLZO, `lzo_crc32` only of the full library; without them the
+//! same checksums are computed in Rust.
+//!
+//! Example
+//!
+//! ```rust
+//! use minilzo::checksum::{self, Adler32};
+//!
+//! let mut adler = Adler32::new();
+//! adler.update(b"foo");
+//! adler.update(b"bar");
+//! assert_eq!(checksum::adler32(b"foobar"), adler.value());
+//! ```
+
+use std::hash::Hasher;
+
+#[cfg(feature = "minilzo-sys")]
+use minilzo_sys::{lzo_adler32, lzo_uint};
+#[cfg(feature = "system-lzo2")]
+use minilzo_sys::{lzo_crc32, lzo_get_crc32_table};
+
+/// Update the Adler-32 checksum `c` with `buf`.
+#[cfg(feature = "minilzo-sys")]
+fn adler32_update(c: u32, buf: &[u8]) -> u32 {
+    unsafe { lzo_adler32(c, buf.as_ptr(), buf.len() as lzo_uint) }
+}
+
+/// Update the Adler-32 checksum `c` with `buf`.
+#[cfg(not(feature = "minilzo-sys"))]
+fn adler32_update(c: u32, buf: &[u8]) -> u32 {
+    const BASE: u32 = 65521;
+    // The largest number of bytes that can be summed before `s2` overflows.
+    const NMAX: usize = 5552;
+
+    let mut s1 = c & 0xffff;
+    let mut s2 = c >> 16;
+    for chunk in buf.chunks(NMAX) {
+        for &b in chunk {
+            s1 += u32::from(b);
+            s2 += s1;
+        }
+        s1 %= BASE;
+        s2 %= BASE;
+    }
+    (s2 << 16) | s1
+}
+
+/// Update the CRC-32 checksum `c` with `buf`.
+#[cfg(feature = "system-lzo2")]
+fn crc32_update(c: u32, buf: &[u8]) -> u32 {
+    unsafe { lzo_crc32(c, buf.as_ptr(), buf.len() as lzo_uint) }
+}
+
+#[cfg(not(feature = "system-lzo2"))]
+static CRC32_TABLE: [u32; 256] = make_crc32_table();
+
+#[cfg(not(feature = "system-lzo2"))]
+const fn make_crc32_table() -> [u32; 256] {
+    let mut table = [0; 256];
+    let mut i = 0;
+    while i < 256 {
+        let mut c = i as u32;
+        let mut k = 0;
+        while k < 8 {
+            c = if c & 1 != 0 { 0xedb88320 ^ (c >> 1) } else { c >> 1 };
+            k += 1;
+        }
+        table[i] = c;
+        i += 1;
+    }
+    table
+}
+
+/// Update the CRC-32 checksum `c` with `buf`.
+#[cfg(not(feature = "system-lzo2"))]
+fn crc32_update(c: u32, buf: &[u8]) -> u32 {
+    let mut c = !c;
+    for &b in buf {
+        c = CRC32_TABLE[((c ^ u32::from(b)) & 0xff) as usize] ^ (c >> 8);
+    }
+    !c
+}
+
+/// The lookup table of the CRC-32, as returned by `lzo_get_crc32_table`.
+#[cfg(feature = "system-lzo2")]
+pub fn crc32_table() -> &'static [u32; 256] {
+    unsafe { &*(lzo_get_crc32_table() as *const [u32; 256]) }
+}
+
+/// The lookup table of the CRC-32, as returned by `lzo_get_crc32_table`.
+#[cfg(not(feature = "system-lzo2"))]
+pub fn crc32_table() -> &'static [u32; 256] {
+    &CRC32_TABLE
+}
+
+/// The Adler-32 checksum of `data`.
+pub fn adler32(data: &[u8]) -> u32 {
+    adler32_update(1, data)
+}
+
+/// The CRC-32 checksum of `data`.
+pub fn crc32(data: &[u8]) -> u32 {
+    crc32_update(0, data)
+}
+
+/// An Adler-32 checksum computed incrementally.
+///
+/// As a `Hasher`, `finish` returns the checksum.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Adler32 {
+    value: u32,
+}
+
+impl Adler32 {
+    /// Start a new checksum.
+    pub fn new() -> Adler32 {
+        Adler32 { value: 1 }
+    }
+
+    /// Continue a checksum from an earlier `value`.
+    pub fn from_value(value: u32) -> Adler32 {
+        Adler32 { value }
+    }
+
+    /// Add `data` to the checksum.
+    pub fn update(&mut self, data: &[u8]) {
+        self.value = adler32_update(self.value, data);
+    }
+
+    /// The checksum of the data so far.
+    pub fn value(&self) -> u32 {
+        self.value
+    }
+}
+
+impl Default for Adler32 {
+    fn default() -> Adler32 {
+        Adler32::new()
+    }
+}
+
+impl Hasher for Adler32 {
+    fn write(&mut self, bytes: &[u8]) {
+        self.update(bytes);
+    }
+
+    fn finish(&self) -> u64 {
+        u64::from(self.value)
+    }
+}
+
+/// A CRC-32 checksum computed incrementally.
+///
+/// As a `Hasher`, `finish` returns the checksum.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Crc32 {
+    value: u32,
+}
+
+impl Crc32 {
+    /// Start a new checksum.
+    pub fn new() -> Crc32 {
+        Crc32 { value: 0 }
+    }
+
+    /// Continue a checksum from an earlier `value`.
+    pub fn from_value(value: u32) -> Crc32 {
+        Crc32 { value }
+    }
+
+    /// Add `data` to the checksum.
+    pub fn update(&mut self, data: &[u8]) {
+        self.value = crc32_update(self.value, data);
+    }
+
+    /// The checksum of the data so far.
+    pub fn value(&self) -> u32 {
+        self.value
+    }
+}
+
+impl Default for Crc32 {
+    fn default() -> Crc32 {
+        Crc32::new()
+    }
+}
+
+impl Hasher for Crc32 {
+    fn write(&mut self, bytes: &[u8]) {
+        self.update(bytes);
+    }
+
+    fn finish(&self) -> u64 {
+        u64::from(self.value)
+    }
+}
+
+#[test]
+fn test_adler32() {
+    assert_eq!(1, adler32(b""));
+    assert_eq!(0x11e60398, adler32(b"Wikipedia"));
+    let data = vec![0xff; 100_000];
+    assert_eq!(0x149a302c, adler32(&data));
+
+    let mut adler = Adler32::new();
+    adler.update(&data[..7777]);
+    let mut adler = Adler32::from_value(adler.value());
+    adler.update(&data[7777..]);
+    assert_eq!(0x149a302c, adler.value());
+}
+
+#[test]
+fn test_crc32() {
+    assert_eq!(0, crc32(b""));
+    assert_eq!(0xcbf43926, crc32(b"123456789"));
+    assert_eq!(0x77073096, crc32_table()[1]);
+
+    let mut crc = Crc32::new();
+    crc.update(b"1234");
+    crc.update(b"56789");
+    assert_eq!(0xcbf43926, crc.value());
+}
+
+#[test]
+fn test_hasher() {
+    let mut crc = Crc32::default();
+    crc.write(b"123456789");
+    assert_eq!(0xcbf43926, crc.finish());
+
+    let mut adler = Adler32::default();
+    adler.write(b"Wikipedia");
+    assert_eq!(0x11e60398, adler.finish());
+}
@@ -0,0 +1,394 @@
+//! Compression with a preset dictionary.
+//!
+//! The dictionary acts as data that precedes the input, so matches can refer
+//! back into it. This helps a lot with small inputs that share structure.
+//! The same dictionary has to be used for compression and decompression.
+
+use std::cmp;
+use std::collections::HashMap;
+use std::mem::size_of;
+
+use libc::c_uchar;
+use minilzo_sys::{
+    // Types
+    lzo_uint,
+
+    // Helpers
+    LZO1X_999_MEM_COMPRESS,
+
+    // (De)compress
+    lzo1x_999_compress_dict,
+    lzo1x_decompress_dict_safe,
+};
+
+use {Error, compress_bound, ensure_init};
+
+/// The largest dictionary LZO1X can refer back into, 48 KiB - 1.
+pub const MAX_DICT_SIZE: usize = 0xbfff;
+
+/// A preset dictionary for `compress_with_dict` and `decompress_with_dict`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Dictionary {
+    bytes: Vec<u8>,
+}
+
+impl Dictionary {
+    /// Create a dictionary from the given bytes.
+    ///
+    /// Matches can only refer back `MAX_DICT_SIZE` bytes, so only the last
+    /// `MAX_DICT_SIZE` bytes of a longer dictionary are kept.
+    pub fn new(bytes: &[u8]) -> Dictionary {
+        let start = bytes.len().saturating_sub(MAX_DICT_SIZE);
+        Dictionary { bytes: bytes[start..].to_vec() }
+    }
+
+    /// The bytes of the dictionary.
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.bytes
+    }
+
+    /// The size of the dictionary in bytes.
+    pub fn len(&self) -> usize {
+        self.bytes.len()
+    }
+
+    /// Whether the dictionary is empty.
+    pub fn is_empty(&self) -> bool {
+        self.bytes.is_empty()
+    }
+
+    fn as_ptr(&self) -> *const c_uchar {
+        self.bytes.as_ptr()
+    }
+}
+
+/// Compress the given data with LZO1X-999 and a preset dictionary, if possible.
+/// An error will be returned if compression fails.
+///
+/// The output can only be decompressed with `decompress_with_dict` and the same dictionary.
+///
+/// Example
+///
+/// ```rust
+/// use minilzo::Dictionary;
+///
+/// let dict = Dictionary::new(br#"{"id": 0, "name": "", "tags": []}"#);
+/// let data = br#"{"id": 17, "name": "foo", "tags": ["bar"]}"#;
+/// let compressed = minilzo::compress_with_dict(&data[..], &dict);
+/// ```
+pub fn compress_with_dict(indata: &[u8], dict: &Dictionary) -> Result<Vec<u8>, Error> {
+    ensure_init()?;
+
+    let mut wrkmem = vec![0u64; LZO1X_999_MEM_COMPRESS / size_of::<u64>()];
+
+    let inlen = indata.len();
+    let mut outdata = Vec::with_capacity(compress_bound(inlen));
+    let mut outlen = outdata.capacity() as lzo_uint;
+
+    let r = unsafe {
+        lzo1x_999_compress_dict(
+            indata.as_ptr(),
+            inlen as lzo_uint,
+            outdata.as_mut_ptr(),
+            &mut outlen,
+            wrkmem.as_mut_ptr() as *mut _,
+            dict.as_ptr(),
+            dict.len() as lzo_uint)
+    };
+
+    if r != 0 {
+        return Err(Error::from_code(r))
+    }
+    let outlen = outlen as usize;
+    if outlen > inlen {
+        return Err(Error::NotCompressible)
+    }
+
+    unsafe { outdata.set_len(outlen) };
+    Ok(outdata)
+}
+
+/// Decompress data produced by `compress_with_dict`, if possible.
+/// An error will be returned if decompression fails.
+///
+/// `newlen` is the size of the output buffer, as for `decompress`.
+///
+/// Example
+///
+/// ```rust,no_run
+/// use minilzo::Dictionary;
+///
+/// let dict = Dictionary::new(br#"{"id": 0, "name": "", "tags": []}"#);
+/// let data = b"[your-compressed-data]";
+/// let decompressed = minilzo::decompress_with_dict(&data[..], &dict, 100);
+/// ```
+pub fn decompress_with_dict(indata: &[u8], dict: &Dictionary, newlen: usize) -> Result<Vec<u8>, Error> {
+    ensure_init()?;
+
+    let mut outdata = Vec::with_capacity(newlen);
+    let mut outlen = newlen as lzo_uint;
+
+    let r = unsafe {
+        lzo1x_decompress_dict_safe(
+            indata.as_ptr(),
+            indata.len() as lzo_uint,
+            outdata.as_mut_ptr(),
+            &mut outlen,
+            ::std::ptr::null_mut(),
+            dict.as_ptr(),
+            dict.len() as lzo_uint)
+    };
+
+    if r != 0 {
+        return Err(Error::from_code(r))
+    }
+
+    unsafe { outdata.set_len(outlen as usize) };
+    Ok(outdata)
+}
+
+/// Builds a `Dictionary` from sample data.
+///
+/// The dictionary is made of segments of the samples that contain the byte
+/// strings most samples share. The most useful segments are put at the end,
+/// where the matches referring to them are shortest.
+///
+/// Example
+///
+/// ```rust
+/// let mut builder = minilzo::DictionaryBuilder::new(4096);
+/// for i in 0..100 {
+///     builder.add_sample(format!(r#"{{"id": {}, "event": "click"}}"#, i).as_bytes());
+/// }
+/// let dict = builder.build();
+/// let report = builder.report(&dict).unwrap();
+/// assert!(report.improvement() > 1.0);
+/// ```
+#[derive(Debug, Clone)]
+pub struct DictionaryBuilder {
+    size: usize,
+    samples: Vec<Vec<u8>>,
+}
+
+// The length of the byte strings whose frequency is counted.
+const DMER_LEN: usize = 8;
+// The length of the segments the dictionary is made of.
+const SEGMENT_LEN: usize = 64;
+// The distance between the starts of candidate segments.
+const SEGMENT_STEP: usize = 8;
+
+fn dmer(data: &[u8], i: usize) -> u64 {
+    let mut b = [0; DMER_LEN];
+    b.copy_from_slice(&data[i..i + DMER_LEN]);
+    u64::from_le_bytes(b)
+}
+
+/// The distinct dmers of `data`.
+fn dmers(data: &[u8]) -> Vec<u64> {
+    let mut dmers: Vec<u64> = (0..data.len().saturating_sub(DMER_LEN - 1)).map(|i| dmer(data, i)).collect();
+    dmers.sort_unstable();
+    dmers.dedup();
+    dmers
+}
+
+impl DictionaryBuilder {
+    /// Create a builder for a dictionary of at most `size` bytes.
+    ///
+    /// Panics if `size` is larger than `MAX_DICT_SIZE`.
+    pub fn new(size: usize) -> DictionaryBuilder {
+        assert!(size <= MAX_DICT_SIZE, "dictionary size {} larger than {}", size, MAX_DICT_SIZE);
+        DictionaryBuilder {
+            size,
+            samples: Vec::new(),
+        }
+    }
+
+    /// Add a sample of the data the dictionary is for.
+    pub fn add_sample(&mut self, sample: &[u8]) {
+        self.samples.push(sample.to_vec());
+    }
+
+    /// The number of samples added so far.
+    pub fn num_samples(&self) -> usize {
+        self.samples.len()
+    }
+
+    /// Build the dictionary from the samples.
+    ///
+    /// The candidate segments are split into as many groups as segments fit
+    /// into the dictionary, and the best one of each group is taken. A segment
+    /// scores by how many samples contain each of its 8 byte strings; strings
+    /// already in the dictionary or found in only one sample do not count.
+    pub fn build(&self) -> Dictionary {
+        // How many samples contain each dmer.
+        let mut freq: HashMap<u64, u32> = HashMap::new();
+        for sample in &self.samples {
+            for d in dmers(sample) {
+                *freq.entry(d).or_insert(0) += 1;
+            }
+        }
+        // A string only one sample contains does not help compress the others.
+        freq.retain(|_, &mut n| n > 1);
+
+        let mut candidates = Vec::new();
+        for sample in &self.samples {
+            let mut start = 0;
+            while start + DMER_LEN <= sample.len() {
+                let end = cmp::min(start + SEGMENT_LEN, sample.len());
+                candidates.push(&sample[start..end]);
+                if end == sample.len() {
+                    break;
+                }
+                start += SEGMENT_STEP;
+            }
+        }
+
+        let epochs = cmp::max(self.size / SEGMENT_LEN, 1);
+        let epoch_len = cmp::max(candidates.len().div_ceil(epochs), 1);
+        let mut segments = Vec::new();
+        let mut len = 0;
+        for epoch in candidates.chunks(epoch_len) {
+            let best = epoch.iter()
+                .map(|segment| (dmers(segment).iter().filter_map(|d| freq.get(d)).map(|&n| u64::from(n)).sum::<u64>(), *segment))
+                .max_by_key(|&(score, _)| score);
+            let (score, segment) = match best {
+                Some((score, segment)) if score > 0 => (score, segment),
+                _ => continue,
+            };
+            for d in dmers(segment) {
+                freq.remove(&d);
+            }
+            segments.push((score, segment));
+            len += segment.len();
+        }
+
+        // The least useful segments are dropped first and go to the front.
+        segments.sort_by_key(|&(score, _)| cmp::Reverse(score));
+        while len > self.size {
+            len -= segments.pop().unwrap().1.len();
+        }
+        let mut bytes = Vec::with_capacity(len);
+        for &(_, segment) in segments.iter().rev() {
+            bytes.extend_from_slice(segment);
+        }
+        Dictionary::new(&bytes)
+    }
+
+    /// Compress every sample with and without `dict` and report the sizes.
+    ///
+    /// Samples that do not compress count with their original size.
+    pub fn report(&self, dict: &Dictionary) -> Result<DictionaryReport, Error> {
+        let no_dict = Dictionary::new(b"");
+        let mut report = DictionaryReport {
+            samples: self.samples.len(),
+            original_len: 0,
+            compressed_len: 0,
+            dict_compressed_len: 0,
+        };
+        for sample in &self.samples {
+            report.original_len += sample.len();
+            report.compressed_len += compressed_len(sample, &no_dict)?;
+            report.dict_compressed_len += compressed_len(sample, dict)?;
+        }
+        Ok(report)
+    }
+}
+
+fn compressed_len(indata: &[u8], dict: &Dictionary) -> Result<usize, Error> {
+    match compress_with_dict(indata, dict) {
+        Ok(compressed) => Ok(compressed.len()),
+        Err(Error::NotCompressible) => Ok(indata.len()),
+        Err(e) => Err(e),
+    }
+}
+
+/// The sizes of the samples of a `DictionaryBuilder`, compressed with and without a dictionary.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DictionaryReport {
+    /// The number of samples.
+    pub samples: usize,
+    /// The total size of the samples.
+    pub original_len: usize,
+    /// The total size of the samples compressed without the dictionary.
+    pub compressed_len: usize,
+    /// The total size of the samples compressed with the dictionary.
+    pub dict_compressed_len: usize,
+}
+
+impl DictionaryReport {
+    /// The compression ratio without the dictionary, original size by compressed size.
+    pub fn ratio(&self) -> f64 {
+        self.original_len as f64 / self.compressed_len as f64
+    }
+
+    /// The compression ratio with the dictionary.
+    pub fn dict_ratio(&self) -> f64 {
+        self.original_len as f64 / self.dict_compressed_len as f64
+    }
+
+    /// How many times better the ratio is with the dictionary.
+    pub fn improvement(&self) -> f64 {
+        self.compressed_len as f64 / self.dict_compressed_len as f64
+    }
+}
+
+#[cfg(test)]
+fn json_message(i: u32) -> Vec<u8> {
+    format!(r#"{{"id": {}, "user": "user{}", "event": "click", "tags": ["web", "mobile"], "ok": true}}"#,
+            i, i % 13).into_bytes()
+}
+
+#[test]
+fn test_dictionary_truncates() {
+    let bytes: Vec<u8> = (0..MAX_DICT_SIZE + 10).map(|i| i as u8).collect();
+    let dict = Dictionary::new(&bytes);
+    assert_eq!(MAX_DICT_SIZE, dict.len());
+    assert_eq!(&bytes[10..], dict.as_bytes());
+}
+
+#[test]
+fn test_dict_round() {
+    let dict = Dictionary::new(&json_message(0));
+    let data = json_message(12345);
+
+    let compressed = compress_with_dict(&data, &dict).unwrap();
+    assert!(compressed.len() < data.len() / 2);
+    assert_eq!(data, decompress_with_dict(&compressed, &dict, data.len()).unwrap());
+
+    // Without the dictionary, the matches point before the start of the output.
+    assert!(::decompress(&compressed, data.len()).is_err());
+}
+
+#[test]
+fn test_dict_empty() {
+    let dict = Dictionary::new(b"");
+    let data = [0; 1000];
+
+    let compressed = compress_with_dict(&data, &dict).unwrap();
+    assert_eq!(&data[..], &::decompress(&compressed, data.len()).unwrap()[..]);
+    assert_eq!(&data[..], &decompress_with_dict(&compressed, &dict, data.len()).unwrap()[..]);
+}
+
+#[test]
+fn test_dictionary_builder() {
+    let mut builder = DictionaryBuilder::new(1024);
+    for i in 0..200 {
+        builder.add_sample(&json_message(i));
+    }
+    let dict = builder.build();
+    assert!(!dict.is_empty() && dict.len() <= 1024);
+
+    let report = builder.report(&dict).unwrap();
+    assert_eq!(200, report.samples);
+    assert!(report.dict_compressed_len * 2 < report.compressed_len);
+    assert!(report.improvement() > 2.0);
+
+    let data = json_message(1000);
+    let compressed = compress_with_dict(&data, &dict).unwrap();
+    assert_eq!(data, decompress_with_dict(&compressed, &dict, data.len()).unwrap());
+}
+
+#[test]
+fn test_dictionary_builder_no_samples() {
+    assert!(DictionaryBuilder::new(MAX_DICT_SIZE).build().is_empty());
+}
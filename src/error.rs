@@ -0,0 +1,154 @@
+use std::error;
+use std::fmt;
+use std::io;
+
+/// Errors of Compression or Decompression
+///
+/// These are the same as minilzo returns,
+/// just nicely wrapped as an enum.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Error {
+    Error,
+    OutOfMemory,
+    NotCompressible,
+    InputOverrun,
+    OutputOverrun,
+    LookbehindOverrun,
+    EOFNotFound,
+    InputNotConsumed,
+    NotYetImplemented,
+    InvalidArgument,
+    InvalidAlignment,
+    OutputNotConsumed,
+    InternalError,
+    /// `lzo_init` failed, the linked library does not match the type sizes Rust uses.
+    InitFailed,
+    /// An error code this crate does not know about.
+    Unknown(i32),
+}
+
+impl Error {
+    pub fn from_code(code: i32) -> Error {
+        match code {
+             -1 => Error::Error,
+             -2 => Error::OutOfMemory,
+             -3 => Error::NotCompressible,
+             -4 => Error::InputOverrun,
+             -5 => Error::OutputOverrun,
+             -6 => Error::LookbehindOverrun,
+             -7 => Error::EOFNotFound,
+             -8 => Error::InputNotConsumed,
+             -9 => Error::NotYetImplemented,
+            -10 => Error::InvalidArgument,
+            -11 => Error::InvalidAlignment,
+            -12 => Error::OutputNotConsumed,
+            -99 => Error::InternalError,
+            _ => Error::Unknown(code),
+        }
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let msg = match *self {
+            Error::Error => "LZO error",
+            Error::OutOfMemory => "out of memory",
+            Error::NotCompressible => "data is not compressible",
+            Error::InputOverrun => "compressed data ended unexpectedly",
+            Error::OutputOverrun => "output buffer too small",
+            Error::LookbehindOverrun => "compressed data refers to data before the start of the output",
+            Error::EOFNotFound => "no end-of-stream marker found",
+            Error::InputNotConsumed => "trailing data after the end-of-stream marker",
+            Error::NotYetImplemented => "not implemented",
+            Error::InvalidArgument => "invalid argument",
+            Error::InvalidAlignment => "pointer argument is not properly aligned",
+            Error::OutputNotConsumed => "output not consumed",
+            Error::InternalError => "internal LZO error",
+            Error::InitFailed => "LZO initialization failed, type sizes do not match the library",
+            Error::Unknown(code) => return write!(f, "unknown LZO error {}", code),
+        };
+        f.write_str(msg)
+    }
+}
+
+impl error::Error for Error {}
+
+impl From<Error> for io::Error {
+    fn from(e: Error) -> io::Error {
+        let kind = match e {
+            Error::OutOfMemory => io::ErrorKind::OutOfMemory,
+            Error::InputOverrun | Error::EOFNotFound => io::ErrorKind::UnexpectedEof,
+            Error::InvalidArgument | Error::InvalidAlignment => io::ErrorKind::InvalidInput,
+            Error::NotYetImplemented => io::ErrorKind::Unsupported,
+            Error::LookbehindOverrun | Error::InputNotConsumed => io::ErrorKind::InvalidData,
+            _ => io::ErrorKind::Other,
+        };
+        io::Error::new(kind, e)
+    }
+}
+
+/// A decompression error together with where it happened.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DecompressError {
+    pub error: Error,
+    /// Offset into the compressed data at which decompression stopped.
+    /// Only the Rust implementation reports this.
+    pub input_offset: Option<usize>,
+    /// Number of bytes written to the output before decompression stopped.
+    pub output_len: usize,
+}
+
+impl fmt::Display for DecompressError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.input_offset {
+            Some(offset) => write!(f, "{} at input offset {} after {} output bytes",
+                                   self.error, offset, self.output_len),
+            None => write!(f, "{} after {} output bytes", self.error, self.output_len),
+        }
+    }
+}
+
+impl error::Error for DecompressError {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        Some(&self.error)
+    }
+}
+
+impl From<DecompressError> for Error {
+    fn from(e: DecompressError) -> Error {
+        e.error
+    }
+}
+
+impl From<DecompressError> for io::Error {
+    fn from(e: DecompressError) -> io::Error {
+        let kind = io::Error::from(e.error).kind();
+        io::Error::new(kind, e)
+    }
+}
+
+#[test]
+fn test_from_code_unknown() {
+    assert_eq!(Error::OutputOverrun, Error::from_code(-5));
+    assert_eq!(Error::Unknown(-42), Error::from_code(-42));
+    assert_eq!("unknown LZO error -42", Error::Unknown(-42).to_string());
+}
+
+#[test]
+fn test_into_io_error() {
+    fn read() -> io::Result<()> {
+        Err(Error::InputOverrun)?;
+        Ok(())
+    }
+
+    let err = read().unwrap_err();
+    assert_eq!(io::ErrorKind::UnexpectedEof, err.kind());
+    assert_eq!("compressed data ended unexpectedly", err.to_string());
+}
+
+#[test]
+fn test_decompress_error_display() {
+    let err = DecompressError { error: Error::LookbehindOverrun, input_offset: Some(7), output_len: 4 };
+    assert_eq!("compressed data refers to data before the start of the output at input offset 7 \
+                after 4 output bytes", err.to_string());
+}
@@ -0,0 +1,335 @@
+//! The block stream of Hadoop's `LzoCodec`.
+//!
+//! The data is split into blocks, each a big-endian `u32` uncompressed length
+//! followed by one or more chunks: a big-endian `u32` compressed length and
+//! the LZO1X compressed data. There is no header and no end marker.
+//!
+//! Hadoop's `LzopCodec` writes the lzop format, see the `lzop` module.
+
+use std::cmp;
+use std::io::{self, BufRead, Read, Write};
+
+use stream::{self, BlockBuf, BlockRead, BlockWrite, invalid_data, read_be32, read_full};
+use {Compressor, compress_bound, decompress_into_with_context};
+
+/// The default buffer size of `LzoCodec`, 256 KiB.
+pub const DEFAULT_BUFFER_SIZE: usize = 256 * 1024;
+
+/// The largest block, Hadoop reads the length of a block as an `int`.
+const MAX_BLOCK_LEN: usize = i32::MAX as usize;
+
+/// The most input `LzoCodec` compresses into one chunk with a buffer of `buffer_size` bytes.
+///
+/// The compressed chunk has to fit into the buffer, so this leaves room for
+/// the worst-case expansion.
+pub fn max_input_size(buffer_size: usize) -> usize {
+    buffer_size - (compress_bound(buffer_size) - buffer_size)
+}
+
+/// A writer for the block stream of `LzoCodec`.
+///
+/// Like Hadoop's `BlockCompressorStream`, data is collected into blocks of up
+/// to `max_input_size(buffer_size)` bytes. A single write larger than that
+/// becomes one block of several chunks, or several such blocks if it is
+/// larger than 2 GiB.
+///
+/// Example
+///
+/// ```rust
+/// use std::io::Write;
+///
+/// let mut writer = minilzo::hadoop::Writer::new(Vec::new());
+/// writer.write_all(b"foobar").unwrap();
+/// let stream = writer.finish().unwrap();
+/// ```
+pub struct Writer<W: Write> {
+    inner: Option<W>,
+    compressor: Compressor,
+    max_input: usize,
+    buf: Vec<u8>,
+    out: Vec<u8>,
+}
+
+impl<W: Write> Writer<W> {
+    /// Create a writer with the default buffer size of 256 KiB.
+    pub fn new(inner: W) -> Writer<W> {
+        Writer::with_buffer_size(inner, DEFAULT_BUFFER_SIZE)
+    }
+
+    /// Create a writer with the buffer size configured for `LzoCodec`,
+    /// `io.compression.codec.lzo.buffersize`.
+    ///
+    /// Panics if `buffer_size` is smaller than 128 bytes or does not fit into 31 bits.
+    pub fn with_buffer_size(inner: W, buffer_size: usize) -> Writer<W> {
+        assert!(buffer_size >= 128 && buffer_size <= i32::MAX as usize, "invalid buffer size {}", buffer_size);
+        let max_input = max_input_size(buffer_size);
+        Writer {
+            inner: Some(inner),
+            compressor: Compressor::new(),
+            max_input,
+            buf: Vec::with_capacity(max_input),
+            out: Vec::new(),
+        }
+    }
+
+    /// A reference to the underlying writer.
+    pub fn get_ref(&self) -> &W {
+        self.inner.as_ref().unwrap()
+    }
+
+    /// A mutable reference to the underlying writer.
+    ///
+    /// Writing to it directly corrupts the stream.
+    pub fn get_mut(&mut self) -> &mut W {
+        self.inner.as_mut().unwrap()
+    }
+
+    /// Write the remaining data and return the underlying writer.
+    pub fn finish(mut self) -> io::Result<W> {
+        stream::finish(&mut self)
+    }
+}
+
+impl<W: Write> BlockWrite for Writer<W> {
+    type Inner = W;
+
+    fn inner(&mut self) -> &mut Option<W> {
+        &mut self.inner
+    }
+
+    /// Write the buffered data as a block of one chunk, if there is any.
+    fn write_block(&mut self) -> io::Result<()> {
+        if self.buf.is_empty() {
+            return Ok(())
+        }
+        let inner = self.inner.as_mut().unwrap();
+        inner.write_all(&(self.buf.len() as u32).to_be_bytes())?;
+        write_chunk(inner, &mut self.compressor, &mut self.out, &self.buf)?;
+        self.buf.clear();
+        Ok(())
+    }
+}
+
+/// Compress `data` and write it as one chunk, using `out` as the buffer.
+fn write_chunk<W: Write>(inner: &mut W, compressor: &mut Compressor, out: &mut Vec<u8>, data: &[u8]) -> io::Result<()> {
+    out.clear();
+    out.extend_from_slice(&[0; 4]);
+    let clen = compressor.compress_vec_append(data, out)?;
+    out[..4].copy_from_slice(&(clen as u32).to_be_bytes());
+    inner.write_all(out)
+}
+
+impl<W: Write> Write for Writer<W> {
+    fn write(&mut self, data: &[u8]) -> io::Result<usize> {
+        if !self.buf.is_empty() && self.buf.len() + data.len() > self.max_input {
+            self.write_block()?;
+        }
+        if data.len() > self.max_input {
+            let data = &data[..cmp::min(data.len(), MAX_BLOCK_LEN)];
+            let inner = self.inner.as_mut().unwrap();
+            inner.write_all(&(data.len() as u32).to_be_bytes())?;
+            for chunk in data.chunks(self.max_input) {
+                write_chunk(inner, &mut self.compressor, &mut self.out, chunk)?;
+            }
+            return Ok(data.len())
+        }
+
+        self.buf.extend_from_slice(data);
+        if self.buf.len() == self.max_input {
+            self.write_block()?;
+        }
+        Ok(data.len())
+    }
+
+    /// Write the buffered data as a possibly short block and flush the underlying writer.
+    fn flush(&mut self) -> io::Result<()> {
+        stream::flush(self)
+    }
+}
+
+impl<W: Write> Drop for Writer<W> {
+    fn drop(&mut self) {
+        stream::drop(self);
+    }
+}
+
+/// A reader for the block stream of `LzoCodec`.
+///
+/// Chunks are decompressed one at a time. As in Hadoop's decompressor, a
+/// chunk may not decompress to more than the buffer size.
+///
+/// Example
+///
+/// ```rust
+/// use std::io::{Read, Write};
+///
+/// let mut writer = minilzo::hadoop::Writer::new(Vec::new());
+/// writer.write_all(b"foobar").unwrap();
+/// let stream = writer.finish().unwrap();
+///
+/// let mut reader = minilzo::hadoop::Reader::new(&stream[..]);
+/// let mut data = Vec::new();
+/// reader.read_to_end(&mut data).unwrap();
+/// assert_eq!(b"foobar", &data[..]);
+/// ```
+pub struct Reader<R: Read> {
+    inner: R,
+    buffer_size: usize,
+    block_remaining: usize,
+    input: Vec<u8>,
+    buf: BlockBuf,
+}
+
+impl<R: Read> Reader<R> {
+    /// Create a reader with the default buffer size of 256 KiB.
+    pub fn new(inner: R) -> Reader<R> {
+        Reader::with_buffer_size(inner, DEFAULT_BUFFER_SIZE)
+    }
+
+    /// Create a reader with the buffer size configured for `LzoCodec`.
+    pub fn with_buffer_size(inner: R, buffer_size: usize) -> Reader<R> {
+        Reader {
+            inner,
+            buffer_size,
+            block_remaining: 0,
+            input: Vec::new(),
+            buf: BlockBuf::new(),
+        }
+    }
+
+    /// A reference to the underlying reader.
+    pub fn get_ref(&self) -> &R {
+        &self.inner
+    }
+
+    /// A mutable reference to the underlying reader.
+    pub fn get_mut(&mut self) -> &mut R {
+        &mut self.inner
+    }
+
+    /// Return the underlying reader.
+    ///
+    /// Data that was already read from it but not yet returned is lost.
+    pub fn into_inner(self) -> R {
+        self.inner
+    }
+}
+
+impl<R: Read> BlockRead for Reader<R> {
+    fn block_buf(&mut self) -> &mut BlockBuf {
+        &mut self.buf
+    }
+
+    /// Read the next chunk into `buf`. Returns `false` at the end of the input.
+    fn next_block(&mut self) -> io::Result<bool> {
+        while self.block_remaining == 0 {
+            let mut len = [0; 4];
+            match read_full(&mut self.inner, &mut len)? {
+                0 => return Ok(false),
+                4 => {}
+                _ => return Err(io::Error::new(io::ErrorKind::UnexpectedEof, "truncated block header")),
+            }
+            self.block_remaining = u32::from_be_bytes(len) as usize;
+        }
+
+        let clen = read_be32(&mut self.inner)? as usize;
+        if clen > compress_bound(self.buffer_size) {
+            return Err(invalid_data("chunk larger than the buffer size"))
+        }
+        self.input.resize(clen, 0);
+        self.inner.read_exact(&mut self.input)?;
+
+        let len = cmp::min(self.block_remaining, self.buffer_size);
+        let buf = self.buf.clear();
+        buf.reserve(len);
+        let n = decompress_into_with_context(&self.input, &mut buf.spare_capacity_mut()[..len])?;
+        unsafe { buf.set_len(n) };
+        if n == 0 {
+            return Err(invalid_data("empty chunk"))
+        }
+        self.block_remaining -= n;
+        Ok(true)
+    }
+}
+
+impl<R: Read> Read for Reader<R> {
+    fn read(&mut self, out: &mut [u8]) -> io::Result<usize> {
+        stream::read(self, out)
+    }
+}
+
+impl<R: Read> BufRead for Reader<R> {
+    fn fill_buf(&mut self) -> io::Result<&[u8]> {
+        stream::fill_buf(self)
+    }
+
+    fn consume(&mut self, amt: usize) {
+        self.buf.consume(amt);
+    }
+}
+
+#[test]
+fn test_max_input_size() {
+    assert_eq!(245693, max_input_size(DEFAULT_BUFFER_SIZE));
+}
+
+#[test]
+fn test_writer_layout() {
+    let mut writer = Writer::new(Vec::new());
+    writer.write_all(b"foo").unwrap();
+    writer.flush().unwrap();
+    writer.write_all(b"bar").unwrap();
+    let stream = writer.finish().unwrap();
+
+    let mut expected = Vec::new();
+    for data in &[b"foo", b"bar"] {
+        let compressed = ::compress_with_mode(&data[..], ::CompressMode::AllowExpansion).unwrap();
+        expected.extend_from_slice(&3u32.to_be_bytes());
+        expected.extend_from_slice(&(compressed.len() as u32).to_be_bytes());
+        expected.extend_from_slice(&compressed);
+    }
+    assert_eq!(expected, stream);
+}
+
+#[test]
+fn test_reader_sub_chunks() {
+    let data: Vec<u8> = (0..1000u32).map(|i| (i % 7) as u8).collect();
+
+    let mut writer = Writer::with_buffer_size(Vec::new(), 256);
+    writer.write_all(&data).unwrap();
+    let stream = writer.finish().unwrap();
+    // one block with several chunks
+    assert_eq!(&1000u32.to_be_bytes(), &stream[..4]);
+
+    let mut out = Vec::new();
+    Reader::with_buffer_size(&stream[..], 256).read_to_end(&mut out).unwrap();
+    assert_eq!(data, out);
+}
+
+#[test]
+fn test_round() {
+    let data = ::test_numbers(100_000, 997);
+
+    let mut writer = Writer::new(Vec::new());
+    for piece in data.chunks(1000) {
+        writer.write_all(piece).unwrap();
+    }
+    let stream = writer.finish().unwrap();
+
+    let mut out = Vec::new();
+    Reader::new(&stream[..]).read_to_end(&mut out).unwrap();
+    assert_eq!(data, out);
+}
+
+#[test]
+fn test_reader_invalid() {
+    let mut out = Vec::new();
+    let err = Reader::new(&b"\0\0\0\x03\x7f\xff\xff\xff"[..]).read_to_end(&mut out).unwrap_err();
+    assert_eq!(io::ErrorKind::InvalidData, err.kind());
+
+    let err = Reader::new(&b"\0\0\0\x03\0\0\0\x03\x11\0"[..]).read_to_end(&mut out).unwrap_err();
+    assert_eq!(io::ErrorKind::UnexpectedEof, err.kind());
+
+    let err = Reader::new(&b"\0\0"[..]).read_to_end(&mut out).unwrap_err();
+    assert_eq!(io::ErrorKind::UnexpectedEof, err.kind());
+}
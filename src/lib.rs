@@ -17,89 +17,234 @@
 //! let decompressed = minilzo::decompress(&compressed, data.len()).unwrap();
 //! ```
 
+#[cfg(not(any(feature = "minilzo-sys", feature = "pure-rust")))]
+compile_error!("either the `minilzo-sys` or the `pure-rust` feature must be enabled");
+
+#[cfg(feature = "minilzo-sys")]
 extern crate minilzo_sys;
 extern crate libc;
 
-use std::mem::size_of;
+mod alg;
+pub mod checksum;
+#[cfg(feature = "system-lzo2")]
+mod dict;
+mod error;
+mod pure;
+mod stream;
+pub mod hadoop;
+pub mod lzop;
+pub mod read;
+pub mod write;
+
+pub use alg::{Algorithm, compress_alg, decompress_alg};
+#[cfg(feature = "system-lzo2")]
+pub use dict::{Dictionary, DictionaryBuilder, DictionaryReport, MAX_DICT_SIZE, compress_with_dict, decompress_with_dict};
+pub use error::{DecompressError, Error};
+
+use std::cell::RefCell;
+use std::cmp;
+use std::fmt;
+use std::mem::{size_of, MaybeUninit};
+#[cfg(all(feature = "minilzo-sys", any(not(feature = "pure-rust"), feature = "system-lzo2")))]
 use std::ptr;
+use std::slice;
+use std::sync::Once;
+use std::sync::atomic::{AtomicI32, Ordering};
 
-use libc::{c_int, c_short, c_long};
+#[cfg(feature = "system-lzo2")]
+use libc::c_int;
+#[cfg(feature = "minilzo-sys")]
+use minilzo_sys::lzo_init;
+#[cfg(all(feature = "minilzo-sys", any(not(feature = "pure-rust"), feature = "system-lzo2")))]
+use minilzo_sys::lzo_uint;
+#[cfg(not(feature = "pure-rust"))]
+use minilzo_sys::lzo_version;
+#[cfg(feature = "system-lzo2")]
+use minilzo_sys::{
+    // Helpers
+    LZO1X_999_MEM_COMPRESS,
+
+    // (De)compress
+    lzo1x_999_compress_level,
+    lzo1x_optimize,
+};
+#[cfg(not(feature = "pure-rust"))]
 use minilzo_sys::{
     // Types
-    lzo_uint,
-    lzo_callback_t,
+    lzo_compress_t,
 
     // Helpers
     LZO1X_1_MEM_COMPRESS,
-    lzo_version,
-    __lzo_init_v2,
+    LZO1X_1_11_MEM_COMPRESS,
+    LZO1X_1_12_MEM_COMPRESS,
+    LZO1X_1_15_MEM_COMPRESS,
 
     // (De)compress
     lzo1x_1_compress,
-    lzo1x_decompress_safe,
 };
+#[cfg(all(feature = "system-lzo2", not(feature = "pure-rust")))]
+use minilzo_sys::{
+    lzo1x_1_11_compress,
+    lzo1x_1_12_compress,
+    lzo1x_1_15_compress,
+};
+
+#[cfg(feature = "minilzo-sys")]
+fn _lzo_init() -> i32 {
+    unsafe { lzo_init() }
+}
 
-/// Errors of Compression or Decompression
-///
-/// These are the same as minilzo returns,
-/// just nicely wrapped as an enum.
-#[derive(Debug, PartialEq)]
-pub enum Error {
-    Error,
-    OutOfMemory,
-    NotCompressible,
-    InputOverrun,
-    OutputOverrun,
-    LookbehindOverrun,
-    EOFNotFound,
-    InputNotConsumed,
-    NotYetImplemented,
-    InvalidArgument,
-    InvalidAlignment,
-    OutputNotConsumed,
-    InternalError,
-}
-
-impl Error {
-    pub fn from_code(code: i32) -> Error {
-        match code {
-             -1 => Error::Error,
-             -2 => Error::OutOfMemory,
-             -3 => Error::NotCompressible,
-             -4 => Error::InputOverrun,
-             -5 => Error::OutputOverrun,
-             -6 => Error::LookbehindOverrun,
-             -7 => Error::EOFNotFound,
-             -8 => Error::InputNotConsumed,
-             -9 => Error::NotYetImplemented,
-            -10 => Error::InvalidArgument,
-            -11 => Error::InvalidAlignment,
-            -12 => Error::OutputNotConsumed,
-            -99 => Error::InternalError,
-            _ => Error::Error,
+#[cfg(not(feature = "minilzo-sys"))]
+fn _lzo_init() -> i32 {
+    0
+}
+
+/// The implementation behind LZO1X compression and decompression.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Backend {
+    /// The miniLZO sources bundled with `minilzo-sys`.
+    Vendored,
+    /// The LZO library installed on the system.
+    System,
+    /// The Rust implementation of the `pure-rust` feature.
+    ///
+    /// It compresses as the C library does on 64-bit little-endian targets,
+    /// so on 32-bit targets its output can differ from that of the C library.
+    PureRust,
+}
+
+impl fmt::Display for Backend {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Backend::Vendored => f.write_str("bundled miniLZO"),
+            Backend::System => f.write_str("system liblzo2"),
+            Backend::PureRust => f.write_str("pure Rust"),
         }
     }
 }
 
-fn _lzo_init() -> i32 {
-    unsafe {
-        __lzo_init_v2(lzo_version(),
-                      size_of::<c_short>() as c_int,
-                      size_of::<c_int>() as c_int,
-                      size_of::<c_long>() as c_int,
-                      size_of::<u32>() as c_int, // lzo_uint32_t
-                      size_of::<lzo_uint>() as c_int,
-                      size_of::<usize>() as c_int, // lzo_sizeof_dict_t
-                      size_of::<usize>() as c_int, // char*
-                      size_of::<usize>() as c_int, // lzo_voidp
-                      size_of::<lzo_callback_t>() as c_int
-                     )
+/// The version of the LZO implementation in use.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Version {
+    pub major: u8,
+    pub minor: u8,
+    /// The version as reported by the library, e.g. `"2.10"`.
+    pub string: &'static str,
+    /// The release date as reported by the library, e.g. `"Mar 01 2017"`.
+    pub date: &'static str,
+    pub backend: Backend,
+}
+
+impl fmt::Display for Version {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "LZO {} ({}, {})", self.string, self.date, self.backend)
+    }
+}
+
+/// Returns the version of the LZO implementation in use.
+///
+/// With the `pure-rust` feature this is the LZO release the Rust
+/// implementation is compatible with.
+///
+/// Example
+///
+/// ```rust
+/// println!("{}", minilzo::version());
+/// ```
+#[cfg(not(feature = "pure-rust"))]
+pub fn version() -> Version {
+    use std::ffi::CStr;
+    use minilzo_sys::{lzo_version_date, lzo_version_string};
+
+    fn to_str(s: *const libc::c_char) -> &'static str {
+        unsafe { CStr::from_ptr(s) }.to_str().unwrap_or("")
+    }
+
+    let v = unsafe { lzo_version() };
+    Version {
+        major: (v >> 12) as u8,
+        minor: (v >> 4) as u8,
+        string: to_str(unsafe { lzo_version_string() }),
+        date: to_str(unsafe { lzo_version_date() }),
+        backend: if minilzo_sys::VENDORED { Backend::Vendored } else { Backend::System },
+    }
+}
+
+/// Returns the version of the LZO implementation in use.
+///
+/// With the `pure-rust` feature this is the LZO release the Rust
+/// implementation is compatible with.
+///
+/// Example
+///
+/// ```rust
+/// println!("{}", minilzo::version());
+/// ```
+#[cfg(feature = "pure-rust")]
+pub fn version() -> Version {
+    Version {
+        major: 2,
+        minor: 10,
+        string: "2.10",
+        date: "Mar 01 2017",
+        backend: Backend::PureRust,
+    }
+}
+
+static INIT: Once = Once::new();
+static INIT_RESULT: AtomicI32 = AtomicI32::new(0);
+
+/// Initialize the LZO library.
+///
+/// This checks that the linked library agrees with Rust on the sizes of
+/// the types they share and returns its version.
+/// If they differ, `InitFailed` is returned and no data must be passed
+/// to the library.
+///
+/// The check only runs once; all (de)compression functions call this
+/// on their own, so calling it is only needed to detect a mismatch early.
+///
+/// Example
+///
+/// ```rust
+/// let version = minilzo::init().unwrap();
+/// ```
+pub fn init() -> Result<Version, Error> {
+    ensure_init()?;
+    Ok(version())
+}
+
+/// Run the check of `init` once, without the cost of building a `Version`.
+fn ensure_init() -> Result<(), Error> {
+    INIT.call_once(|| INIT_RESULT.store(_lzo_init(), Ordering::SeqCst));
+
+    if INIT_RESULT.load(Ordering::SeqCst) != 0 {
+        return Err(Error::InitFailed)
     }
+    Ok(())
+}
+
+/// Returns the worst-case size of the compressed output for `len` bytes of input.
+///
+/// A buffer of this size is always large enough to hold the output of
+/// `compress_into`.
+///
+/// Example
+///
+/// ```rust
+/// let data = b"foobar";
+/// let mut buf = vec![0; minilzo::compress_bound(data.len())];
+/// ```
+pub fn compress_bound(len: usize) -> usize {
+    len + len / 16 + 64 + 3
 }
 
 /// Compress the given data, if possible.
 /// An error will be returned if compression fails.
 ///
+/// The work memory is allocated once per thread and reused by the free
+/// compression functions.
+///
 /// Example
 ///
 /// ```rust
@@ -107,33 +252,466 @@ fn _lzo_init() -> i32 {
 /// let compressed = minilzo::compress(&data[..]);
 /// ```
 pub fn compress(indata: &[u8]) -> Result<Vec<u8>, Error> {
-    let mut wrkmem : [u8; LZO1X_1_MEM_COMPRESS] = unsafe {
-        std::mem::uninitialized()
-    };
+    with_compressor(|c| c.compress(indata))
+}
+
+thread_local! {
+    static COMPRESSOR: RefCell<Compressor> = RefCell::new(Compressor::new());
+}
+
+/// Run `f` with the LZO1X-1 compressor of this thread.
+fn with_compressor<T, F: FnOnce(&mut Compressor) -> T>(f: F) -> T {
+    COMPRESSOR.with(|c| f(&mut c.borrow_mut()))
+}
+
+/// Compress the given data into a caller-provided buffer.
+///
+/// Returns the number of bytes written to `outdata`.
+/// The buffer must hold at least `compress_bound(indata.len())` bytes,
+/// otherwise `OutputOverrun` is returned without compressing anything.
+///
+/// Unlike `compress`, the output is returned even if it is larger than the input.
+///
+/// Example
+///
+/// ```rust
+/// let data = b"foobar";
+/// let mut buf = vec![0; minilzo::compress_bound(data.len())];
+/// let len = minilzo::compress_into(&data[..], &mut buf).unwrap();
+/// let compressed = &buf[..len];
+/// ```
+pub fn compress_into(indata: &[u8], outdata: &mut [u8]) -> Result<usize, Error> {
+    with_compressor(|c| c.compress_into(indata, outdata))
+}
+
+/// Compress the given data into a possibly uninitialized buffer.
+///
+/// Behaves like `compress_into`. On success the first `n` bytes of `outdata`
+/// are initialized, where `n` is the returned length.
+pub fn compress_into_uninit(indata: &[u8], outdata: &mut [MaybeUninit<u8>]) -> Result<usize, Error> {
+    with_compressor(|c| c.compress_into_uninit(indata, outdata))
+}
+
+/// What `compress` does when the compressed data is larger than the input.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum CompressMode {
+    /// Fail with `NotCompressible`.
+    #[default]
+    RejectExpansion,
+    /// Always return the LZO stream, even if it is larger than the input.
+    AllowExpansion,
+}
+
+/// Compress the given data, handling expansion as `mode` says.
+///
+/// Example
+///
+/// ```rust
+/// use minilzo::CompressMode;
+///
+/// let data = b"foo";
+/// let compressed = minilzo::compress_with_mode(&data[..], CompressMode::AllowExpansion).unwrap();
+/// assert!(compressed.len() > data.len());
+/// ```
+pub fn compress_with_mode(indata: &[u8], mode: CompressMode) -> Result<Vec<u8>, Error> {
+    with_compressor(|c| {
+        c.set_mode(mode);
+        let result = c.compress(indata);
+        c.set_mode(CompressMode::default());
+        result
+    })
+}
+
+/// The result of `compress_or_store`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MaybeCompressed {
+    /// The LZO1X stream, shorter than the input.
+    Compressed(Vec<u8>),
+    /// Compression did not help, the input should be stored as it is.
+    Stored,
+}
+
+impl MaybeCompressed {
+    /// Whether the input should be stored uncompressed.
+    pub fn is_stored(&self) -> bool {
+        *self == MaybeCompressed::Stored
+    }
+
+    /// The bytes to store: the compressed data, or `indata` itself if it is `Stored`.
+    ///
+    /// `indata` must be the data that was passed to `compress_or_store`.
+    pub fn as_bytes<'a>(&'a self, indata: &'a [u8]) -> &'a [u8] {
+        match *self {
+            MaybeCompressed::Compressed(ref data) => data,
+            MaybeCompressed::Stored => indata,
+        }
+    }
+}
+
+/// Compress the given data, or tell the caller to store it uncompressed.
+///
+/// `Stored` is returned if the compressed data would not be shorter than the input.
+/// Use `decompress_or_load` to get the data back.
+///
+/// Example
+///
+/// ```rust
+/// let data = b"foobar";
+/// let result = minilzo::compress_or_store(&data[..]).unwrap();
+/// let stored = result.as_bytes(&data[..]);
+///
+/// let decompressed = minilzo::decompress_or_load(stored, result.is_stored(), data.len()).unwrap();
+/// assert_eq!(&data[..], &decompressed[..]);
+/// ```
+pub fn compress_or_store(indata: &[u8]) -> Result<MaybeCompressed, Error> {
+    with_compressor(|c| c.compress_or_store(indata))
+}
+
+/// Compress the given data with the LZO1X-999 algorithm, if possible.
+/// An error will be returned if compression fails.
+///
+/// LZO1X-999 is much slower than `compress` but achieves a better ratio.
+/// It produces the same LZO1X format, so the output can be decompressed
+/// with `decompress`.
+///
+/// `level` ranges from 1 (fastest) to 9 (best compression),
+/// any other value returns `InvalidArgument`.
+///
+/// Example
+///
+/// ```rust
+/// let data = b"foobar";
+/// let compressed = minilzo::compress_with_level(&data[..], 9);
+/// ```
+#[cfg(feature = "system-lzo2")]
+pub fn compress_with_level(indata: &[u8], level: u8) -> Result<Vec<u8>, Error> {
+    ensure_init()?;
+    if !(1..=9).contains(&level) {
+        return Err(Error::InvalidArgument)
+    }
+
+    let mut wrkmem = vec![0u64; LZO1X_999_MEM_COMPRESS / size_of::<u64>()];
 
     let inlen = indata.len();
-    let outlen = inlen + inlen / 16 + 64 + 3;
-    let mut outdata = Vec::with_capacity(outlen);
+    let mut outdata = Vec::with_capacity(compress_bound(inlen));
+    let mut outlen = outdata.capacity() as lzo_uint;
 
-    unsafe {
-        let r = lzo1x_1_compress(
+    let r = unsafe {
+        lzo1x_999_compress_level(
             indata.as_ptr(),
             inlen as lzo_uint,
             outdata.as_mut_ptr(),
-            &outlen as *const _ as *mut _,
-            wrkmem.as_mut_ptr() as *mut _);
+            &mut outlen,
+            wrkmem.as_mut_ptr() as *mut _,
+            ptr::null(),
+            0,
+            ptr::null_mut(),
+            level as c_int)
+    };
 
-        if r == 0 {
-            if outlen > inlen {
-                return Err(Error::NotCompressible)
-            }
+    if r != 0 {
+        return Err(Error::from_code(r))
+    }
+    let outlen = outlen as usize;
+    if outlen > inlen {
+        return Err(Error::NotCompressible)
+    }
 
-            outdata.set_len(outlen);
-            return Ok(outdata)
-        }
+    unsafe { outdata.set_len(outlen) };
+    Ok(outdata)
+}
+
+/// Rewrite LZO1X compressed data in place so that it decompresses faster.
+///
+/// The optimized data decompresses to the same `original_len` bytes with
+/// `decompress`. It is verified before `data` is replaced; on any error
+/// `data` is left untouched. Data that does not decompress to exactly
+/// `original_len` bytes fails with `OutputOverrun` or `OutputNotConsumed`.
+///
+/// Example
+///
+/// ```rust
+/// let data = [0; 1024];
+/// let mut compressed = minilzo::compress(&data[..]).unwrap();
+/// minilzo::optimize(&mut compressed, data.len()).unwrap();
+/// ```
+#[cfg(feature = "system-lzo2")]
+pub fn optimize(data: &mut Vec<u8>, original_len: usize) -> Result<(), Error> {
+    // lzo1x_optimize does not check its input, so make sure it is valid first.
+    let original = decompress(data, original_len)?;
+    if original.len() != original_len {
+        return Err(Error::OutputNotConsumed)
+    }
+
+    let mut optimized = data.clone();
+    let mut outdata = Vec::with_capacity(original_len);
+    let mut outlen = original_len as lzo_uint;
 
+    let r = unsafe {
+        lzo1x_optimize(
+            optimized.as_mut_ptr(),
+            optimized.len() as lzo_uint,
+            outdata.as_mut_ptr(),
+            &mut outlen,
+            ptr::null_mut())
+    };
+
+    if r != 0 {
         return Err(Error::from_code(r))
     }
+    if decompress(&optimized, original_len)? != original {
+        return Err(Error::InternalError)
+    }
+
+    *data = optimized;
+    Ok(())
+}
+
+/// The LZO1X-1 compression variants.
+///
+/// All of them produce the LZO1X format and can be decompressed with `decompress`.
+/// They differ in the size of the dictionary and thus the work memory they need;
+/// a smaller dictionary usually means a worse compression ratio.
+///
+/// The bundled miniLZO only provides `Lzo1x_1`. Unless the `pure-rust` or
+/// `system-lzo2` feature is enabled, the other variants fail with `NotYetImplemented`.
+#[allow(non_camel_case_types)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum CompressionAlgorithm {
+    /// `lzo1x_1_compress`, 16384 dictionary entries.
+    #[default]
+    Lzo1x_1,
+    /// `lzo1x_1_11_compress`, 2048 dictionary entries.
+    Lzo1x_1_11,
+    /// `lzo1x_1_12_compress`, 4096 dictionary entries.
+    Lzo1x_1_12,
+    /// `lzo1x_1_15_compress`, 32768 dictionary entries.
+    Lzo1x_1_15,
+}
+
+impl CompressionAlgorithm {
+    /// Size of the work memory in bytes this algorithm needs.
+    #[cfg(not(feature = "pure-rust"))]
+    pub fn work_memory_size(self) -> usize {
+        match self {
+            CompressionAlgorithm::Lzo1x_1 => LZO1X_1_MEM_COMPRESS,
+            CompressionAlgorithm::Lzo1x_1_11 => LZO1X_1_11_MEM_COMPRESS,
+            CompressionAlgorithm::Lzo1x_1_12 => LZO1X_1_12_MEM_COMPRESS,
+            CompressionAlgorithm::Lzo1x_1_15 => LZO1X_1_15_MEM_COMPRESS,
+        }
+    }
+
+    /// Size of the work memory in bytes this algorithm needs.
+    #[cfg(feature = "pure-rust")]
+    pub fn work_memory_size(self) -> usize {
+        pure::dict_len(self.dict_bits()) * size_of::<u16>()
+    }
+
+    #[cfg(all(feature = "system-lzo2", not(feature = "pure-rust")))]
+    fn compress_fn(self) -> lzo_compress_t {
+        match self {
+            CompressionAlgorithm::Lzo1x_1 => Some(lzo1x_1_compress),
+            CompressionAlgorithm::Lzo1x_1_11 => Some(lzo1x_1_11_compress),
+            CompressionAlgorithm::Lzo1x_1_12 => Some(lzo1x_1_12_compress),
+            CompressionAlgorithm::Lzo1x_1_15 => Some(lzo1x_1_15_compress),
+        }
+    }
+
+    #[cfg(all(not(feature = "system-lzo2"), not(feature = "pure-rust")))]
+    fn compress_fn(self) -> lzo_compress_t {
+        match self {
+            CompressionAlgorithm::Lzo1x_1 => Some(lzo1x_1_compress),
+            _ => None,
+        }
+    }
+
+    #[cfg(feature = "pure-rust")]
+    fn dict_bits(self) -> u32 {
+        match self {
+            CompressionAlgorithm::Lzo1x_1 => 14,
+            CompressionAlgorithm::Lzo1x_1_11 => 11,
+            CompressionAlgorithm::Lzo1x_1_12 => 12,
+            CompressionAlgorithm::Lzo1x_1_15 => 15,
+        }
+    }
+}
+
+/// Compress the given data with the chosen algorithm, if possible.
+/// An error will be returned if compression fails.
+///
+/// Example
+///
+/// ```rust
+/// use minilzo::CompressionAlgorithm;
+///
+/// let data = b"foobar";
+/// let compressed = minilzo::compress_with_algorithm(&data[..], CompressionAlgorithm::Lzo1x_1_11);
+/// ```
+pub fn compress_with_algorithm(indata: &[u8], algorithm: CompressionAlgorithm) -> Result<Vec<u8>, Error> {
+    Compressor::with_algorithm(algorithm).compress(indata)
+}
+
+/// A reusable LZO1X-1 compressor.
+///
+/// The work memory needed by the chosen `CompressionAlgorithm` is allocated
+/// once on the heap and reused for every call.
+///
+/// Example
+///
+/// ```rust
+/// let mut compressor = minilzo::Compressor::new();
+/// for data in &[&b"foobar"[..], &b"barfoo"[..]] {
+///     let _ = compressor.compress(data);
+/// }
+/// ```
+pub struct Compressor {
+    algorithm: CompressionAlgorithm,
+    mode: CompressMode,
+    // u64 keeps the work memory aligned as lzo_align_t.
+    #[cfg(not(feature = "pure-rust"))]
+    wrkmem: Box<[MaybeUninit<u64>]>,
+    #[cfg(feature = "pure-rust")]
+    dict: Box<[u16]>,
+}
+
+impl Compressor {
+    /// Create a new LZO1X-1 compressor and allocate its work memory.
+    pub fn new() -> Compressor {
+        Compressor::with_algorithm(CompressionAlgorithm::default())
+    }
+
+    /// Create a new compressor for the given algorithm and allocate its work memory.
+    #[cfg(not(feature = "pure-rust"))]
+    pub fn with_algorithm(algorithm: CompressionAlgorithm) -> Compressor {
+        Compressor {
+            algorithm,
+            mode: CompressMode::default(),
+            // Not zeroed: the compressor clears the dictionary before each chunk
+            // where that matters for the output, otherwise it checks every match it finds.
+            wrkmem: Box::new_uninit_slice(algorithm.work_memory_size() / size_of::<u64>()),
+        }
+    }
+
+    /// Create a new compressor for the given algorithm and allocate its work memory.
+    #[cfg(feature = "pure-rust")]
+    pub fn with_algorithm(algorithm: CompressionAlgorithm) -> Compressor {
+        Compressor {
+            algorithm,
+            mode: CompressMode::default(),
+            dict: vec![0; pure::dict_len(algorithm.dict_bits())].into_boxed_slice(),
+        }
+    }
+
+    /// The algorithm this compressor uses.
+    pub fn algorithm(&self) -> CompressionAlgorithm {
+        self.algorithm
+    }
+
+    /// How `compress` handles data that does not compress.
+    pub fn mode(&self) -> CompressMode {
+        self.mode
+    }
+
+    /// Set how `compress` handles data that does not compress.
+    pub fn set_mode(&mut self, mode: CompressMode) {
+        self.mode = mode;
+    }
+
+    /// Compress the given data, if possible.
+    ///
+    /// Behaves like the free function `compress`, or `compress_with_mode`
+    /// if a mode was set.
+    pub fn compress(&mut self, indata: &[u8]) -> Result<Vec<u8>, Error> {
+        let mut outdata = Vec::new();
+        let outlen = self.compress_vec_append(indata, &mut outdata)?;
+        if outlen > indata.len() && self.mode == CompressMode::RejectExpansion {
+            return Err(Error::NotCompressible)
+        }
+
+        Ok(outdata)
+    }
+
+    /// Compress the given data, or tell the caller to store it uncompressed.
+    ///
+    /// Behaves like the free function `compress_or_store`.
+    pub fn compress_or_store(&mut self, indata: &[u8]) -> Result<MaybeCompressed, Error> {
+        let mut outdata = Vec::new();
+        let outlen = self.compress_vec_append(indata, &mut outdata)?;
+        if outlen >= indata.len() {
+            return Ok(MaybeCompressed::Stored)
+        }
+
+        Ok(MaybeCompressed::Compressed(outdata))
+    }
+
+    /// Compress the given data into a caller-provided buffer.
+    ///
+    /// Behaves like the free function `compress_into`.
+    pub fn compress_into(&mut self, indata: &[u8], outdata: &mut [u8]) -> Result<usize, Error> {
+        let outdata = unsafe {
+            slice::from_raw_parts_mut(outdata.as_mut_ptr() as *mut MaybeUninit<u8>, outdata.len())
+        };
+        self.compress_into_uninit(indata, outdata)
+    }
+
+    /// Compress the given data into a possibly uninitialized buffer.
+    ///
+    /// Behaves like the free function `compress_into_uninit`.
+    pub fn compress_into_uninit(&mut self, indata: &[u8], outdata: &mut [MaybeUninit<u8>]) -> Result<usize, Error> {
+        ensure_init()?;
+        if outdata.len() < compress_bound(indata.len()) {
+            return Err(Error::OutputOverrun)
+        }
+        self.lzo1x_compress(indata, outdata)
+    }
+
+    #[cfg(not(feature = "pure-rust"))]
+    fn lzo1x_compress(&mut self, indata: &[u8], outdata: &mut [MaybeUninit<u8>]) -> Result<usize, Error> {
+        let mut outlen = outdata.len() as lzo_uint;
+        let compress_fn = match self.algorithm.compress_fn() {
+            Some(compress_fn) => compress_fn,
+            None => return Err(Error::NotYetImplemented),
+        };
+
+        let r = unsafe {
+            compress_fn(
+                indata.as_ptr(),
+                indata.len() as lzo_uint,
+                outdata.as_mut_ptr() as *mut u8,
+                &mut outlen,
+                self.wrkmem.as_mut_ptr() as *mut _)
+        };
+
+        if r != 0 {
+            return Err(Error::from_code(r))
+        }
+        Ok(outlen as usize)
+    }
+
+    #[cfg(feature = "pure-rust")]
+    fn lzo1x_compress(&mut self, indata: &[u8], outdata: &mut [MaybeUninit<u8>]) -> Result<usize, Error> {
+        Ok(pure::compress(indata, outdata, &mut self.dict, self.algorithm.dict_bits()))
+    }
+
+    /// Compress the given data and append it to `outdata`.
+    ///
+    /// Returns the number of bytes appended.
+    /// The output is appended even if it is larger than the input.
+    pub fn compress_vec_append(&mut self, indata: &[u8], outdata: &mut Vec<u8>) -> Result<usize, Error> {
+        let start = outdata.len();
+        outdata.reserve(compress_bound(indata.len()));
+
+        let outlen = self.compress_into_uninit(indata, outdata.spare_capacity_mut())?;
+
+        unsafe { outdata.set_len(start + outlen) };
+        Ok(outlen)
+    }
+}
+
+impl Default for Compressor {
+    fn default() -> Compressor {
+        Compressor::new()
+    }
 }
 
 /// Decompress the given data, if possible.
@@ -152,37 +730,279 @@ pub fn compress(indata: &[u8]) -> Result<Vec<u8>, Error> {
 /// let decompressed = minilzo::decompress(&data[..], 100);
 /// ```
 pub fn decompress(indata: &[u8], newlen: usize) -> Result<Vec<u8>, Error> {
-    let inlen = indata.len();
     let mut outdata = Vec::with_capacity(newlen);
 
-    unsafe {
-        let r = lzo1x_decompress_safe(
+    let outlen = decompress_into_uninit(indata, &mut outdata.spare_capacity_mut()[..newlen])?;
+
+    unsafe { outdata.set_len(outlen) };
+    Ok(outdata)
+}
+
+/// Get back data produced by `compress_or_store`.
+///
+/// If `stored` is set, `indata` is the original data and is copied,
+/// otherwise it is decompressed into at most `newlen` bytes.
+/// Stored data longer than `newlen` returns `OutputOverrun`.
+pub fn decompress_or_load(indata: &[u8], stored: bool, newlen: usize) -> Result<Vec<u8>, Error> {
+    if !stored {
+        return decompress(indata, newlen)
+    }
+    if indata.len() > newlen {
+        return Err(Error::OutputOverrun)
+    }
+    Ok(indata.to_vec())
+}
+
+/// Compress the given data in the LZO-RLE format of the Linux kernel.
+///
+/// LZO-RLE is the LZO1X variant zram and zswap use by default. It starts
+/// with a version byte and encodes runs of zeros specially; the output is
+/// byte-identical to the kernel's `lzorle1x_1_compress` on 64-bit little-endian
+/// targets. A 32-bit kernel can compress differently, which the kernel still decompresses.
+/// Unlike `compress`, the output is returned even if it is larger than the input.
+///
+/// Example
+///
+/// ```rust
+/// let page = [0; 4096];
+/// let compressed = minilzo::compress_rle(&page[..]).unwrap();
+/// assert_eq!(Some(1), minilzo::rle_version(&compressed));
+/// ```
+pub fn compress_rle(indata: &[u8]) -> Result<Vec<u8>, Error> {
+    let mut dict = vec![0; pure::dict_len(pure::RLE_DICT_BITS)];
+    let mut outdata = Vec::with_capacity(compress_bound(indata.len()) + 2);
+
+    let outlen = pure::compress_rle(indata, outdata.spare_capacity_mut(), &mut dict);
+
+    unsafe { outdata.set_len(outlen) };
+    Ok(outdata)
+}
+
+/// Decompress LZO-RLE or plain LZO1X data, as the Linux kernel does.
+///
+/// The format is detected from the version byte at the start of `indata`.
+/// `newlen` is the size of the output buffer, as for `decompress`.
+///
+/// Example
+///
+/// ```rust
+/// let page = [0; 4096];
+/// let compressed = minilzo::compress_rle(&page[..]).unwrap();
+/// let decompressed = minilzo::decompress_rle(&compressed, page.len()).unwrap();
+/// ```
+pub fn decompress_rle(indata: &[u8], newlen: usize) -> Result<Vec<u8>, Error> {
+    let mut outdata = Vec::with_capacity(newlen);
+
+    let outlen = pure::decompress_rle(indata, &mut outdata.spare_capacity_mut()[..newlen])?;
+
+    unsafe { outdata.set_len(outlen) };
+    Ok(outdata)
+}
+
+/// The LZO-RLE bitstream version of the given compressed data.
+///
+/// Returns `None` for plain LZO1X data, `Some(1)` for what `compress_rle` writes.
+pub fn rle_version(indata: &[u8]) -> Option<u8> {
+    pure::rle_version(indata)
+}
+
+/// Decompress the given data without knowing its original length.
+///
+/// The output buffer starts at a guess based on the input length and is
+/// doubled every time decompression runs out of space.
+/// It never grows beyond `max_len` bytes; if the data does not fit into that,
+/// a `OutputOverrun` is returned.
+///
+/// Example:
+///
+/// ```rust,no_run
+/// let data = b"[your-compressed-data]";
+/// let decompressed = minilzo::decompress_unknown_size(&data[..], 16 * 1024 * 1024);
+/// ```
+pub fn decompress_unknown_size(indata: &[u8], max_len: usize) -> Result<Vec<u8>, Error> {
+    let mut outdata = Vec::new();
+    let mut newlen = cmp::min(cmp::max(indata.len().saturating_mul(4), 4096), max_len);
+
+    loop {
+        outdata.reserve(newlen);
+        match decompress_into_uninit(indata, &mut outdata.spare_capacity_mut()[..newlen]) {
+            Ok(outlen) => {
+                unsafe { outdata.set_len(outlen) };
+                return Ok(outdata)
+            }
+            Err(Error::OutputOverrun) if newlen < max_len => {
+                newlen = cmp::min(newlen.saturating_mul(2), max_len);
+            }
+            Err(e) => return Err(e),
+        }
+    }
+}
+
+/// Decompress the given data into a caller-provided buffer.
+///
+/// Returns the number of bytes written to `outdata`.
+/// If `outdata` is not large enough to hold the decompressed data,
+/// a `OutputOverrun` is returned.
+///
+/// Example:
+///
+/// ```rust,no_run
+/// let data = b"[your-compressed-data]";
+/// let mut buf = [0; 100];
+/// let len = minilzo::decompress_into(&data[..], &mut buf).unwrap();
+/// let decompressed = &buf[..len];
+/// ```
+pub fn decompress_into(indata: &[u8], outdata: &mut [u8]) -> Result<usize, Error> {
+    let outdata = unsafe {
+        slice::from_raw_parts_mut(outdata.as_mut_ptr() as *mut MaybeUninit<u8>, outdata.len())
+    };
+    decompress_into_uninit(indata, outdata)
+}
+
+/// Decompress the given data into a possibly uninitialized buffer.
+///
+/// Behaves like `decompress_into`. On success the first `n` bytes of `outdata`
+/// are initialized, where `n` is the returned length.
+pub fn decompress_into_uninit(indata: &[u8], outdata: &mut [MaybeUninit<u8>]) -> Result<usize, Error> {
+    decompress_into_with_context(indata, outdata).map_err(|e| e.error)
+}
+
+/// Decompress the given data into a possibly uninitialized buffer,
+/// reporting where decompression stopped if it fails.
+///
+/// Behaves like `decompress_into_uninit`, but on failure the error carries
+/// the number of bytes written to `outdata` and, where the backend reports it,
+/// the offset into `indata`.
+///
+/// Example:
+///
+/// ```rust
+/// use std::mem::MaybeUninit;
+///
+/// let mut buf = [MaybeUninit::uninit(); 100];
+/// let err = minilzo::decompress_into_with_context(b"\x00", &mut buf).unwrap_err();
+/// assert_eq!(minilzo::Error::InputOverrun, err.error);
+/// ```
+pub fn decompress_into_with_context(indata: &[u8], outdata: &mut [MaybeUninit<u8>]) -> Result<usize, DecompressError> {
+    ensure_init().map_err(|error| DecompressError { error, input_offset: None, output_len: 0 })?;
+    lzo1x_decompress(indata, outdata)
+}
+
+#[cfg(feature = "pure-rust")]
+use pure::decompress as lzo1x_decompress;
+
+#[cfg(not(feature = "pure-rust"))]
+fn lzo1x_decompress(indata: &[u8], outdata: &mut [MaybeUninit<u8>]) -> Result<usize, DecompressError> {
+    use minilzo_sys::lzo1x_decompress_safe;
+
+    let mut outlen = outdata.len() as lzo_uint;
+
+    let r = unsafe {
+        lzo1x_decompress_safe(
             indata.as_ptr(),
-            inlen as lzo_uint,
-            outdata.as_mut_ptr(),
-            &newlen as *const _ as *mut _,
-            ptr::null_mut());
+            indata.len() as lzo_uint,
+            outdata.as_mut_ptr() as *mut u8,
+            &mut outlen,
+            ptr::null_mut())
+    };
+
+    if r != 0 {
+        return Err(DecompressError {
+            error: Error::from_code(r),
+            input_offset: None,
+            output_len: outlen as usize,
+        })
+    }
+    Ok(outlen as usize)
+}
 
-        if r == 0 {
-            outdata.set_len(newlen);
-            return Ok(outdata)
+/// `count` numbers cycling through `0..modulus`, separated by spaces; they compress well.
+#[cfg(test)]
+fn test_numbers(count: u32, modulus: u32) -> Vec<u8> {
+    let mut data = Vec::new();
+    for i in 0..count {
+        data.extend_from_slice(format!("{} ", i % modulus).as_bytes());
+    }
+    data
+}
+
+/// `len` pseudo-random bytes that do not compress.
+#[cfg(test)]
+fn test_noise(len: usize) -> Vec<u8> {
+    let mut seed: u32 = 1;
+    (0..len).map(|_| {
+        seed = seed.wrapping_mul(1103515245).wrapping_add(12345);
+        (seed >> 24) as u8
+    }).collect()
+}
+
+/// A writer that fails every write after the first `ok_writes` and counts them all.
+#[cfg(test)]
+struct FailingWriter {
+    ok_writes: usize,
+    writes: usize,
+}
+
+#[cfg(test)]
+impl std::io::Write for FailingWriter {
+    fn write(&mut self, data: &[u8]) -> std::io::Result<usize> {
+        self.writes += 1;
+        if self.writes > self.ok_writes {
+            return Err(std::io::Error::other("failing writer"))
         }
+        Ok(data.len())
+    }
 
-        return Err(Error::from_code(r))
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
     }
 }
 
 #[test]
-fn init() {
-    // We test this, but we don't export it to the user right now
+fn test_lzo_init() {
     assert_eq!(0, _lzo_init());
 }
 
+#[test]
+fn test_init_version() {
+    let version = init().unwrap();
+    assert_eq!(2, version.major);
+    assert_eq!(Ok(version), init());
+}
+
 #[test]
 fn test_compress_skips_short() {
     assert_eq!(Err(Error::NotCompressible), compress("foo".as_bytes()));
 }
 
+#[test]
+fn test_compress_allow_expansion() {
+    let compressed = compress_with_mode(b"foo", CompressMode::AllowExpansion).unwrap();
+    assert!(compressed.len() > 3);
+    assert_eq!(b"foo", &decompress(&compressed, 3).unwrap()[..]);
+
+    let mut compressor = Compressor::new();
+    assert_eq!(Err(Error::NotCompressible), compressor.compress(b"foo"));
+    compressor.set_mode(CompressMode::AllowExpansion);
+    assert_eq!(Ok(compressed), compressor.compress(b"foo"));
+}
+
+#[test]
+fn test_compress_or_store() {
+    let result = compress_or_store(b"foo").unwrap();
+    assert_eq!(MaybeCompressed::Stored, result);
+    assert_eq!(b"foo", result.as_bytes(b"foo"));
+    assert_eq!(b"foo", &decompress_or_load(b"foo", true, 3).unwrap()[..]);
+    assert_eq!(Err(Error::OutputOverrun), decompress_or_load(b"foo", true, 2));
+
+    let data = [0; 128*1024];
+    let result = compress_or_store(&data[..]).unwrap();
+    assert!(!result.is_stored());
+    let stored = result.as_bytes(&data[..]);
+    assert_eq!(593, stored.len());
+    assert_eq!(&data[..], &decompress_or_load(stored, false, data.len()).unwrap()[..]);
+}
+
 #[test]
 fn test_compress_fails_with_short_output() {
     let data = [0; 128*1024];
@@ -229,3 +1049,200 @@ fn test_alice_wonderland_both() {
     assert_eq!(alice.len(), decompressed.len());
     assert_eq!(alice.as_bytes(), &decompressed[..]);
 }
+
+#[test]
+fn test_compress_into_decompress_into() {
+    let data = [0; 128*1024];
+    let mut compressed = vec![0; compress_bound(data.len())];
+    let len = compress_into(&data[..], &mut compressed).unwrap();
+    assert_eq!(593, len);
+
+    let mut decompressed = vec![1; 128*1024];
+    assert_eq!(128*1024, decompress_into(&compressed[..len], &mut decompressed).unwrap());
+    assert_eq!(&data[..], &decompressed[..]);
+}
+
+#[test]
+fn test_compress_into_short_buffer() {
+    let data = [0; 1024];
+    let mut compressed = vec![0; 1024];
+    assert_eq!(Err(Error::OutputOverrun), compress_into(&data[..], &mut compressed));
+}
+
+#[test]
+fn test_compress_into_keeps_expansion() {
+    let mut compressed = [0; 70];
+    let len = compress_into(b"foo", &mut compressed).unwrap();
+    assert!(len > 3);
+
+    let mut decompressed = [MaybeUninit::uninit(); 3];
+    assert_eq!(3, decompress_into_uninit(&compressed[..len], &mut decompressed).unwrap());
+}
+
+#[test]
+fn test_compressor_reuse() {
+    let mut compressor = Compressor::new();
+    let data = [0; 128*1024];
+
+    assert_eq!(593, compressor.compress(&data[..]).unwrap().len());
+    assert_eq!(593, compressor.compress(&data[..]).unwrap().len());
+    assert_eq!(Err(Error::NotCompressible), compressor.compress(b"foo"));
+}
+
+#[test]
+fn test_thread_compressor() {
+    let data = test_numbers(100_000, 97);
+    let expected = Compressor::new().compress(&data).unwrap();
+
+    assert!(compress_with_mode(b"foo", CompressMode::AllowExpansion).is_ok());
+    // The mode is not kept for the next call.
+    assert_eq!(Err(Error::NotCompressible), compress(b"foo"));
+    assert_eq!(expected, compress(&data).unwrap());
+
+    let other = std::thread::spawn(move || compress(&data).unwrap()).join().unwrap();
+    assert_eq!(expected, other);
+}
+
+#[test]
+fn test_compressor_vec_append() {
+    let mut compressor = Compressor::new();
+    let data = [0; 128*1024];
+
+    let mut out = b"header".to_vec();
+    let len = compressor.compress_vec_append(&data[..], &mut out).unwrap();
+    assert_eq!(6 + len, out.len());
+    assert_eq!(b"header", &out[..6]);
+    assert_eq!(&data[..], &decompress(&out[6..], data.len()).unwrap()[..]);
+}
+
+#[test]
+fn test_compressor_is_send() {
+    fn assert_send<T: Send>() {}
+    assert_send::<Compressor>();
+}
+
+#[test]
+fn test_decompress_unknown_size() {
+    let data = [0; 128*1024];
+    let compressed = compress(&data[..]).unwrap();
+
+    let decompressed = decompress_unknown_size(&compressed, 1024*1024).unwrap();
+    assert_eq!(&data[..], &decompressed[..]);
+}
+
+#[test]
+fn test_decompress_unknown_size_limit() {
+    let data = [0; 128*1024];
+    let compressed = compress(&data[..]).unwrap();
+
+    assert_eq!(Err(Error::OutputOverrun),
+               decompress_unknown_size(&compressed, 128*1024 - 1));
+    assert_eq!(128*1024, decompress_unknown_size(&compressed, 128*1024).unwrap().len());
+}
+
+#[cfg(feature = "system-lzo2")]
+#[test]
+fn test_compress_with_level_round() {
+    let data = test_numbers(100_000, 997);
+    let fast = compress(&data).unwrap();
+
+    let mut sizes = Vec::new();
+    for level in 1..10 {
+        let compressed = compress_with_level(&data, level).unwrap();
+        assert_eq!(data, decompress(&compressed, data.len()).unwrap());
+        sizes.push(compressed.len());
+    }
+    assert!(sizes[8] <= sizes[0]);
+    assert!(sizes[8] < fast.len());
+}
+
+#[cfg(feature = "system-lzo2")]
+#[test]
+fn test_compress_with_level_invalid() {
+    assert_eq!(Err(Error::InvalidArgument), compress_with_level(b"foobar", 0));
+    assert_eq!(Err(Error::InvalidArgument), compress_with_level(b"foobar", 10));
+}
+
+#[cfg(feature = "system-lzo2")]
+#[test]
+fn test_optimize() {
+    let data = test_numbers(10_000, 97);
+    let mut compressed = compress(&data).unwrap();
+    let len = compressed.len();
+    optimize(&mut compressed, data.len()).unwrap();
+    assert_eq!(len, compressed.len());
+    assert_eq!(data, decompress(&compressed, data.len()).unwrap());
+
+    let before = compressed.clone();
+    assert_eq!(Err(Error::OutputNotConsumed), optimize(&mut compressed, data.len() + 1));
+    assert_eq!(Err(Error::OutputOverrun), optimize(&mut compressed, data.len() - 1));
+    assert!(optimize(&mut compressed[..len - 3].to_vec(), data.len()).is_err());
+    assert_eq!(before, compressed);
+}
+
+#[cfg(any(feature = "pure-rust", feature = "system-lzo2"))]
+#[test]
+fn test_compression_algorithms_round() {
+    let data = [0; 128*1024];
+    let algorithms = [
+        CompressionAlgorithm::Lzo1x_1,
+        CompressionAlgorithm::Lzo1x_1_11,
+        CompressionAlgorithm::Lzo1x_1_12,
+        CompressionAlgorithm::Lzo1x_1_15,
+    ];
+
+    for &algorithm in &algorithms {
+        let compressed = compress_with_algorithm(&data[..], algorithm).unwrap();
+        assert_eq!(&data[..], &decompress(&compressed, data.len()).unwrap()[..]);
+    }
+}
+
+#[cfg(not(any(feature = "pure-rust", feature = "system-lzo2")))]
+#[test]
+fn test_compression_algorithms_unavailable() {
+    assert!(compress_with_algorithm(&[0; 1024], CompressionAlgorithm::Lzo1x_1).is_ok());
+    assert_eq!(Err(Error::NotYetImplemented),
+               compress_with_algorithm(&[0; 1024], CompressionAlgorithm::Lzo1x_1_11));
+}
+
+#[test]
+fn test_compress_rle_round() {
+    let mut data = Vec::new();
+    for i in 0..50_000u32 {
+        if i % 7 == 0 {
+            data.extend_from_slice(&[0; 3000][..(i as usize * 31) % 3000]);
+        }
+        data.extend_from_slice(format!("{} ", i % 997).as_bytes());
+    }
+
+    let compressed = compress_rle(&data).unwrap();
+    assert_eq!(Some(1), rle_version(&compressed));
+    assert_eq!(data, decompress_rle(&compressed, data.len()).unwrap());
+
+    // Plain LZO1X is read as well, but LZO-RLE is not plain LZO1X.
+    let plain = compress(&data).unwrap();
+    assert_eq!(None, rle_version(&plain));
+    assert_eq!(data, decompress_rle(&plain, data.len()).unwrap());
+    assert!(decompress(&compressed, data.len()).is_err());
+}
+
+#[test]
+fn test_version() {
+    let version = version();
+    assert_eq!(2, version.major);
+    assert!(version.string.starts_with("2."));
+    assert!(!version.date.is_empty());
+    assert_eq!(format!("LZO {} ({}, {})", version.string, version.date, version.backend),
+               version.to_string());
+}
+
+#[test]
+fn test_decompress_error_context() {
+    let data = [0; 128*1024];
+    let compressed = compress(&data[..]).unwrap();
+
+    let mut out = vec![MaybeUninit::uninit(); 1000];
+    let err = decompress_into_with_context(&compressed, &mut out).unwrap_err();
+    assert_eq!(Error::OutputOverrun, err.error);
+    assert!(err.output_len <= 1000);
+}
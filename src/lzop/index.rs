@@ -0,0 +1,134 @@
+//! The `.lzo.index` files of hadoop-lzo.
+//!
+//! An index lists the offset of every block of an lzop file as a big-endian
+//! `u64`, so that the file can be split at block boundaries.
+
+use std::io::{self, Read, Seek, SeekFrom, Write};
+
+use stream::invalid_data;
+#[cfg(test)]
+use super::*;
+use super::reader::{read_block_header, read_header};
+
+/// Scan an lzop file and return the offset of each of its blocks.
+///
+/// Only the block headers are read; the data is skipped, not decompressed
+/// or verified. `file` has to be positioned at the start of the file.
+///
+/// Example
+///
+/// ```rust,no_run
+/// use std::fs::File;
+///
+/// let offsets = minilzo::lzop::index(File::open("data.lzo").unwrap()).unwrap();
+/// minilzo::lzop::write_index(&offsets, File::create("data.lzo.index").unwrap()).unwrap();
+/// ```
+pub fn index<R: Read + Seek>(mut file: R) -> io::Result<Vec<u64>> {
+    let flags = read_header(&mut file)?.flags;
+
+    let mut offsets = Vec::new();
+    loop {
+        let offset = file.stream_position()?;
+        let h = match read_block_header(&mut file, flags)? {
+            Some(h) => h,
+            None => break,
+        };
+        offsets.push(offset);
+        file.seek(SeekFrom::Current(h.clen as i64))?;
+    }
+    Ok(offsets)
+}
+
+/// Write block offsets in the `.lzo.index` format.
+pub fn write_index<W: Write>(offsets: &[u64], mut w: W) -> io::Result<()> {
+    for offset in offsets {
+        w.write_all(&offset.to_be_bytes())?;
+    }
+    Ok(())
+}
+
+/// Read block offsets from a `.lzo.index` file.
+pub fn read_index<R: Read>(mut r: R) -> io::Result<Vec<u64>> {
+    let mut data = Vec::new();
+    r.read_to_end(&mut data)?;
+    if data.len() % 8 != 0 {
+        return Err(invalid_data("truncated lzo index"))
+    }
+    Ok(data.chunks(8).map(|b| u64::from_be_bytes([b[0], b[1], b[2], b[3], b[4], b[5], b[6], b[7]])).collect())
+}
+
+#[cfg(test)]
+fn test_file(checksums: u32) -> (Vec<u8>, Vec<u8>) {
+    let mut data = ::test_numbers(10_000, 97);
+    // an incompressible block in the middle
+    data.extend(::test_noise(4096));
+    data.extend_from_within(..10_000);
+
+    let options = Options { checksums, block_size: 4096, ..Options::default() };
+    let mut writer = Writer::with_options(Vec::new(), &options).unwrap();
+    writer.write_all(&data).unwrap();
+    (data, writer.finish().unwrap())
+}
+
+#[test]
+fn test_index() {
+    use std::io::Cursor;
+
+    for &checksums in &[0, F_ADLER32_D, F_ADLER32_D | F_ADLER32_C | F_CRC32_D | F_CRC32_C] {
+        let (data, file) = test_file(checksums);
+        let offsets = index(Cursor::new(&file)).unwrap();
+        assert_eq!(data.len().div_ceil(4096), offsets.len());
+
+        for (i, &offset) in offsets.iter().enumerate() {
+            let len = u32::from_be_bytes([file[offset as usize], file[offset as usize + 1],
+                                          file[offset as usize + 2], file[offset as usize + 3]]);
+            assert_eq!(::std::cmp::min(4096, data.len() - i * 4096), len as usize);
+        }
+
+        let mut index_file = Vec::new();
+        write_index(&offsets, &mut index_file).unwrap();
+        assert_eq!(offsets.len() * 8, index_file.len());
+        assert_eq!(offsets, read_index(&index_file[..]).unwrap());
+    }
+}
+
+#[test]
+fn test_reader_seek_to_block() {
+    use std::io::Cursor;
+
+    let (data, file) = test_file(F_ADLER32_D | F_CRC32_C);
+    let offsets = index(Cursor::new(&file)).unwrap();
+
+    let mut reader = Reader::new(Cursor::new(&file)).unwrap();
+    let mut first = [0; 10];
+    reader.read_exact(&mut first).unwrap();
+
+    for &i in &[3, 0, offsets.len() - 1] {
+        reader.seek_to_block(offsets[i]).unwrap();
+        let mut out = Vec::new();
+        reader.read_to_end(&mut out).unwrap();
+        assert_eq!(&data[i * 4096..], &out[..]);
+    }
+}
+
+#[test]
+fn test_index_invalid() {
+    use std::io::Cursor;
+
+    let (_, file) = test_file(F_ADLER32_D);
+    let offsets = index(Cursor::new(&file)).unwrap();
+    let block = offsets[1] as usize;
+
+    let mut split = file.clone();
+    split[block..block + 4].copy_from_slice(&[0xff; 4]);
+    assert_eq!(io::ErrorKind::Unsupported, index(Cursor::new(&split)).unwrap_err().kind());
+
+    let mut too_large = file.clone();
+    too_large[block..block + 4].copy_from_slice(&(MAX_BLOCK_SIZE as u32 + 1).to_be_bytes());
+    assert_eq!(io::ErrorKind::InvalidData, index(Cursor::new(&too_large)).unwrap_err().kind());
+}
+
+#[test]
+fn test_read_index_truncated() {
+    assert_eq!(io::ErrorKind::InvalidData, read_index(&[0; 12][..]).unwrap_err().kind());
+}
@@ -0,0 +1,119 @@
+//! The file format of the `lzop` tool.
+//!
+//! A `.lzo` file starts with `MAGIC` and a `Header`, followed by blocks of
+//! at most `MAX_BLOCK_SIZE` bytes, each compressed with LZO1X on its own,
+//! and ends with a block of length 0. All numbers are big-endian.
+
+mod index;
+mod reader;
+mod writer;
+
+pub use self::index::{index, read_index, write_index};
+pub use self::reader::Reader;
+pub use self::writer::{Options, Writer, DEFAULT_BLOCK_SIZE};
+
+/// The magic bytes at the start of every lzop file.
+pub const MAGIC: [u8; 9] = [0x89, b'L', b'Z', b'O', 0x00, 0x0d, 0x0a, 0x1a, 0x0a];
+
+/// The largest block lzop reads and writes, 64 MiB.
+pub const MAX_BLOCK_SIZE: usize = 64 * 1024 * 1024;
+
+/// The lzop version this crate writes and the newest one it reads, 1.04.
+pub const VERSION: u16 = 0x1040;
+
+/// Method `LZO1X-1`.
+pub const M_LZO1X_1: u8 = 1;
+/// Method `LZO1X-1(15)`.
+pub const M_LZO1X_1_15: u8 = 2;
+/// Method `LZO1X-999`.
+pub const M_LZO1X_999: u8 = 3;
+
+/// Adler-32 checksum of the uncompressed data of each block.
+pub const F_ADLER32_D: u32 = 0x0000_0001;
+/// Adler-32 checksum of the compressed data of each block.
+pub const F_ADLER32_C: u32 = 0x0000_0002;
+/// The input was read from stdin.
+pub const F_STDIN: u32 = 0x0000_0004;
+/// The output was written to stdout.
+pub const F_STDOUT: u32 = 0x0000_0008;
+/// The filename was derived from the name of the compressed file.
+pub const F_NAME_DEFAULT: u32 = 0x0000_0010;
+/// The file was written on a DOS-like system.
+pub const F_DOSISH: u32 = 0x0000_0020;
+/// The header is followed by an extra field.
+pub const F_H_EXTRA_FIELD: u32 = 0x0000_0040;
+/// The mtime is in local time.
+pub const F_H_GMTDIFF: u32 = 0x0000_0080;
+/// CRC-32 checksum of the uncompressed data of each block.
+pub const F_CRC32_D: u32 = 0x0000_0100;
+/// CRC-32 checksum of the compressed data of each block.
+pub const F_CRC32_C: u32 = 0x0000_0200;
+/// The file is part of a multipart archive.
+pub const F_MULTIPART: u32 = 0x0000_0400;
+/// The data was run through a filter before compression.
+pub const F_H_FILTER: u32 = 0x0000_0800;
+/// The header checksum is a CRC-32 instead of an Adler-32.
+pub const F_H_CRC32: u32 = 0x0000_1000;
+/// The filename contains a path.
+pub const F_H_PATH: u32 = 0x0000_2000;
+/// The operating system the file was written on, `F_OS_UNIX` is `0x0300_0000`.
+pub const F_OS_MASK: u32 = 0xff00_0000;
+/// Unix, as lzop records it in the flags.
+pub const F_OS_UNIX: u32 = 0x0300_0000;
+
+/// The header of an lzop file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Header {
+    /// The lzop version that wrote the file.
+    pub version: u16,
+    /// The LZO library version that wrote the file.
+    pub lib_version: u16,
+    /// The lzop version needed to extract the file.
+    pub version_needed: u16,
+    /// The compression method, one of the `M_*` constants.
+    pub method: u8,
+    /// The compression level, 0 for files older than lzop 0.94.
+    pub level: u8,
+    /// The `F_*` flags.
+    pub flags: u32,
+    /// The Unix mode of the original file.
+    pub mode: u32,
+    /// The modification time of the original file in seconds since the epoch.
+    pub mtime: u64,
+    /// The name of the original file, empty if it was not recorded.
+    pub filename: Vec<u8>,
+}
+
+/// Run the `lzop` tool with `input` on stdin and return its stdout.
+///
+/// Returns `None` if `lzop` is not installed, so the tests that compare
+/// against it are skipped. Panics if it fails.
+#[cfg(test)]
+fn run_lzop(args: &[&str], input: &[u8]) -> Option<Vec<u8>> {
+    use std::io::{self, Write};
+    use std::process::{Command, Stdio};
+    use std::thread;
+
+    let mut child = match Command::new("lzop")
+        .args(args)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .spawn() {
+        Ok(child) => child,
+        Err(ref e) if e.kind() == io::ErrorKind::NotFound => {
+            eprintln!("lzop not found, skipping");
+            return None
+        }
+        Err(e) => panic!("failed to run lzop: {}", e),
+    };
+
+    // Written from another thread, lzop may fill its stdout before it has read all of stdin.
+    let mut stdin = child.stdin.take().unwrap();
+    let input = input.to_vec();
+    let writer = thread::spawn(move || stdin.write_all(&input));
+
+    let output = child.wait_with_output().unwrap();
+    assert!(output.status.success(), "lzop {:?} failed: {}", args, output.status);
+    writer.join().unwrap().unwrap();
+    Some(output.stdout)
+}
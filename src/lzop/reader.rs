@@ -0,0 +1,404 @@
+use std::io::{self, BufRead, Read, Seek, SeekFrom};
+
+use checksum::{adler32, crc32};
+use decompress_into_with_context;
+use stream::{self, BlockBuf, BlockRead, invalid_data, read_be32};
+use super::*;
+
+fn unsupported(msg: &str) -> io::Error {
+    io::Error::new(io::ErrorKind::Unsupported, msg)
+}
+
+/// Reads header fields and keeps the bytes for the header checksum.
+struct HeaderReader<'a, R: 'a> {
+    inner: &'a mut R,
+    data: Vec<u8>,
+}
+
+impl<'a, R: Read> HeaderReader<'a, R> {
+    fn bytes(&mut self, n: usize) -> io::Result<&[u8]> {
+        let start = self.data.len();
+        self.data.resize(start + n, 0);
+        self.inner.read_exact(&mut self.data[start..])?;
+        Ok(&self.data[start..])
+    }
+
+    fn u8(&mut self) -> io::Result<u8> {
+        Ok(self.bytes(1)?[0])
+    }
+
+    fn u16(&mut self) -> io::Result<u16> {
+        let b = self.bytes(2)?;
+        Ok(u16::from_be_bytes([b[0], b[1]]))
+    }
+
+    fn u32(&mut self) -> io::Result<u32> {
+        let b = self.bytes(4)?;
+        Ok(u32::from_be_bytes([b[0], b[1], b[2], b[3]]))
+    }
+
+    /// Read the stored checksum and compare it to the checksum of everything read so far.
+    fn verify(&mut self, crc: bool) -> io::Result<()> {
+        let expected = if crc {
+            crc32(&self.data)
+        } else {
+            adler32(&self.data)
+        };
+        if read_be32(self.inner)? != expected {
+            return Err(invalid_data("lzop header checksum mismatch"))
+        }
+        self.data.clear();
+        Ok(())
+    }
+}
+
+pub fn read_header<R: Read>(inner: &mut R) -> io::Result<Header> {
+    let mut magic = [0; 9];
+    inner.read_exact(&mut magic)?;
+    if magic != MAGIC {
+        return Err(invalid_data("not an lzop file"))
+    }
+
+    let mut r = HeaderReader { inner, data: Vec::new() };
+    let version = r.u16()?;
+    if version < 0x0900 {
+        return Err(invalid_data("invalid lzop version"))
+    }
+    let lib_version = r.u16()?;
+    let mut version_needed = 0x0900;
+    if version >= 0x0940 {
+        version_needed = r.u16()?;
+        if version_needed > VERSION {
+            return Err(unsupported("lzop file needs a newer version to extract"))
+        }
+        if version_needed < 0x0900 {
+            return Err(invalid_data("invalid lzop version"))
+        }
+    }
+    let method = r.u8()?;
+    let level = if version >= 0x0940 { r.u8()? } else { 0 };
+    let flags = r.u32()?;
+    if flags & F_H_FILTER != 0 {
+        return Err(unsupported("lzop filters are not supported"))
+    }
+    let mode = r.u32()?;
+    let mut mtime = u64::from(r.u32()?);
+    if version >= 0x0940 {
+        mtime |= u64::from(r.u32()?) << 32;
+    }
+    let len = r.u8()? as usize;
+    let filename = r.bytes(len)?.to_vec();
+    r.verify(flags & F_H_CRC32 != 0)?;
+
+    match method {
+        M_LZO1X_1 | M_LZO1X_1_15 | M_LZO1X_999 => {}
+        _ => return Err(unsupported("unknown lzop compression method")),
+    }
+
+    if flags & F_H_EXTRA_FIELD != 0 {
+        let len = r.u32()? as usize;
+        if len > MAX_BLOCK_SIZE {
+            return Err(invalid_data("lzop extra field too large"))
+        }
+        r.bytes(len)?;
+        r.verify(flags & F_H_CRC32 != 0)?;
+    }
+
+    Ok(Header {
+        version,
+        lib_version,
+        version_needed,
+        method,
+        level,
+        flags,
+        mode,
+        mtime,
+        filename,
+    })
+}
+
+/// The lengths and checksums that precede the data of a block.
+pub struct BlockHeader {
+    /// The uncompressed length.
+    pub len: usize,
+    /// The length of the data; equal to `len` if the block is stored.
+    pub clen: usize,
+    d_adler: Option<u32>,
+    d_crc: Option<u32>,
+    c_adler: Option<u32>,
+    c_crc: Option<u32>,
+}
+
+/// Read the header of the next block of a file with the given flags.
+/// Returns `None` at the end marker.
+pub fn read_block_header<R: Read>(r: &mut R, flags: u32) -> io::Result<Option<BlockHeader>> {
+    let len = read_be32(r)? as usize;
+    if len == 0 {
+        return Ok(None)
+    }
+    if len == 0xffff_ffff {
+        return Err(unsupported("split lzop files are not supported"))
+    }
+    if len > MAX_BLOCK_SIZE {
+        return Err(invalid_data("lzop block too large"))
+    }
+    let clen = read_be32(r)? as usize;
+    if clen == 0 || clen > len {
+        return Err(invalid_data("invalid lzop block size"))
+    }
+
+    let d_adler = if flags & F_ADLER32_D != 0 { Some(read_be32(r)?) } else { None };
+    let d_crc = if flags & F_CRC32_D != 0 { Some(read_be32(r)?) } else { None };
+    let mut c_adler = None;
+    let mut c_crc = None;
+    if clen < len {
+        if flags & F_ADLER32_C != 0 {
+            c_adler = Some(read_be32(r)?);
+        }
+        if flags & F_CRC32_C != 0 {
+            c_crc = Some(read_be32(r)?);
+        }
+    }
+
+    Ok(Some(BlockHeader { len, clen, d_adler, d_crc, c_adler, c_crc }))
+}
+
+/// A reader for `.lzo` files written by `lzop`.
+///
+/// The header is parsed by `new`. Blocks are decompressed as they are read,
+/// and their checksums are verified if the file has them.
+///
+/// Example
+///
+/// ```rust,no_run
+/// use std::fs::File;
+/// use std::io::Read;
+///
+/// let mut reader = minilzo::lzop::Reader::new(File::open("data.lzo").unwrap()).unwrap();
+/// let mut data = Vec::new();
+/// reader.read_to_end(&mut data).unwrap();
+/// ```
+pub struct Reader<R: Read> {
+    inner: R,
+    header: Header,
+    input: Vec<u8>,
+    buf: BlockBuf,
+}
+
+impl<R: Read> Reader<R> {
+    /// Read the lzop header from `inner`.
+    pub fn new(mut inner: R) -> io::Result<Reader<R>> {
+        let header = read_header(&mut inner)?;
+        Ok(Reader {
+            inner,
+            header,
+            input: Vec::new(),
+            buf: BlockBuf::new(),
+        })
+    }
+
+    /// The header of the file.
+    pub fn header(&self) -> &Header {
+        &self.header
+    }
+
+    /// A reference to the underlying reader.
+    pub fn get_ref(&self) -> &R {
+        &self.inner
+    }
+
+    /// A mutable reference to the underlying reader.
+    pub fn get_mut(&mut self) -> &mut R {
+        &mut self.inner
+    }
+
+    /// Return the underlying reader.
+    ///
+    /// Data that was already read from it but not yet returned is lost.
+    pub fn into_inner(self) -> R {
+        self.inner
+    }
+}
+
+impl<R: Read> BlockRead for Reader<R> {
+    fn block_buf(&mut self) -> &mut BlockBuf {
+        &mut self.buf
+    }
+
+    /// Read the next block into `buf`. Returns `false` at the end marker.
+    fn next_block(&mut self) -> io::Result<bool> {
+        let h = match read_block_header(&mut self.inner, self.header.flags)? {
+            Some(h) => h,
+            None => return Ok(false),
+        };
+        let len = h.len;
+
+        let buf = self.buf.clear();
+        if h.clen == len {
+            buf.resize(len, 0);
+            self.inner.read_exact(buf)?;
+        } else {
+            self.input.resize(h.clen, 0);
+            self.inner.read_exact(&mut self.input)?;
+            verify(h.c_adler, h.c_crc, &self.input, "lzop compressed block checksum mismatch")?;
+
+            buf.reserve(len);
+            let n = decompress_into_with_context(&self.input, &mut buf.spare_capacity_mut()[..len])?;
+            unsafe { buf.set_len(n) };
+            if n != len {
+                return Err(invalid_data("lzop block shorter than its header says"))
+            }
+        }
+        verify(h.d_adler, h.d_crc, buf, "lzop block checksum mismatch")?;
+        Ok(true)
+    }
+}
+
+impl<R: Read + Seek> Reader<R> {
+    /// Continue reading at the block that starts at `offset` in the file.
+    ///
+    /// `offset` must be the start of a block, as listed by `index`.
+    /// Buffered data of the current block is discarded.
+    ///
+    /// Example
+    ///
+    /// ```rust,no_run
+    /// use std::fs::File;
+    ///
+    /// let offsets = minilzo::lzop::index(File::open("data.lzo").unwrap()).unwrap();
+    /// let mut reader = minilzo::lzop::Reader::new(File::open("data.lzo").unwrap()).unwrap();
+    /// reader.seek_to_block(offsets[offsets.len() / 2]).unwrap();
+    /// ```
+    pub fn seek_to_block(&mut self, offset: u64) -> io::Result<()> {
+        self.inner.seek(SeekFrom::Start(offset))?;
+        self.buf.clear();
+        self.buf.done = false;
+        Ok(())
+    }
+}
+
+fn verify(adler: Option<u32>, crc: Option<u32>, data: &[u8], msg: &str) -> io::Result<()> {
+    if let Some(adler) = adler {
+        if adler32(data) != adler {
+            return Err(invalid_data(msg))
+        }
+    }
+    if let Some(crc) = crc {
+        if crc32(data) != crc {
+            return Err(invalid_data(msg))
+        }
+    }
+    Ok(())
+}
+
+impl<R: Read> Read for Reader<R> {
+    fn read(&mut self, out: &mut [u8]) -> io::Result<usize> {
+        stream::read(self, out)
+    }
+}
+
+impl<R: Read> BufRead for Reader<R> {
+    fn fill_buf(&mut self) -> io::Result<&[u8]> {
+        stream::fill_buf(self)
+    }
+
+    fn consume(&mut self, amt: usize) {
+        self.buf.consume(amt);
+    }
+}
+
+#[cfg(test)]
+pub const HELLO: &[u8] = b"hello lzop, hello lzop, hello lzop, hello lzop, hello lzop!\nfoo";
+
+// HELLO with Adler-32 checksums: a compressed and a stored block.
+// These bytes were not written by the lzop tool, `test_reader_lzop` reads
+// files lzop writes; they pin the output of `Writer` and cover the error paths.
+#[cfg(test)]
+pub const HELLO_ADLER32: &[u8] = b"\
+    \x89\x4c\x5a\x4f\x00\x0d\x0a\x1a\x0a\x10\x40\x20\xa0\x09\x40\x01\x03\x03\x00\x00\x03\x00\x00\x81\
+    \xa4\x59\x68\x2f\x00\x00\x00\x00\x00\x09\x68\x65\x6c\x6c\x6f\x2e\x74\x78\x74\x63\xd3\x07\x24\x00\
+    \x00\x00\x3c\x00\x00\x00\x29\x96\x8b\x15\x39\x00\xfc\x0b\x8c\x09\x68\x65\x6c\x6c\x6f\x20\x6c\x7a\
+    \x6f\x70\x2c\x20\x3a\x2c\x00\x00\x02\x6f\x20\x6c\x7a\x6f\x70\x2c\x20\x68\x65\x6c\x6c\x6f\x20\x6c\
+    \x7a\x6f\x70\x21\x0a\x11\x00\x00\x00\x00\x00\x03\x00\x00\x00\x03\x02\x82\x01\x45\x66\x6f\x6f\x00\
+    \x00\x00\x00";
+
+// The same file with CRC-32 checksums, including the header checksum.
+#[cfg(test)]
+pub const HELLO_CRC32: &[u8] = b"\
+    \x89\x4c\x5a\x4f\x00\x0d\x0a\x1a\x0a\x10\x40\x20\xa0\x09\x40\x01\x03\x03\x00\x13\x00\x00\x00\x81\
+    \xa4\x59\x68\x2f\x00\x00\x00\x00\x00\x09\x68\x65\x6c\x6c\x6f\x2e\x74\x78\x74\xb0\xb9\xf6\x48\x00\
+    \x00\x00\x3c\x00\x00\x00\x29\x9f\x03\x4a\xb5\x54\xd3\x32\xbc\x09\x68\x65\x6c\x6c\x6f\x20\x6c\x7a\
+    \x6f\x70\x2c\x20\x3a\x2c\x00\x00\x02\x6f\x20\x6c\x7a\x6f\x70\x2c\x20\x68\x65\x6c\x6c\x6f\x20\x6c\
+    \x7a\x6f\x70\x21\x0a\x11\x00\x00\x00\x00\x00\x03\x00\x00\x00\x03\x8c\x73\x65\x21\x66\x6f\x6f\x00\
+    \x00\x00\x00";
+
+#[cfg(test)]
+fn read_all(file: &[u8]) -> io::Result<Vec<u8>> {
+    let mut data = Vec::new();
+    Reader::new(file)?.read_to_end(&mut data)?;
+    Ok(data)
+}
+
+#[test]
+fn test_reader_header() {
+    let reader = Reader::new(HELLO_ADLER32).unwrap();
+    assert_eq!(&Header {
+        version: 0x1040,
+        lib_version: 0x20a0,
+        version_needed: 0x0940,
+        method: M_LZO1X_1,
+        level: 3,
+        flags: F_OS_UNIX | F_ADLER32_C | F_ADLER32_D,
+        mode: 0o100644,
+        mtime: 1500000000,
+        filename: b"hello.txt".to_vec(),
+    }, reader.header());
+}
+
+#[test]
+fn test_reader_data() {
+    assert_eq!(HELLO, &read_all(HELLO_ADLER32).unwrap()[..]);
+    assert_eq!(HELLO, &read_all(HELLO_CRC32).unwrap()[..]);
+    assert_eq!(F_H_CRC32 | F_CRC32_C | F_CRC32_D, Reader::new(HELLO_CRC32).unwrap().header().flags & !F_OS_MASK);
+}
+
+#[test]
+fn test_reader_lzop() {
+    let data = ::test_numbers(600_000, 997);
+    let noise = ::test_noise(100_000);
+    // The default, LZO1X-1(15), LZO1X-999 and the other checksums.
+    let args: &[&[&str]] = &[&["-c"], &["-c", "-1"], &["-c", "-9"], &["-c", "--crc32"], &["-c", "-F"]];
+    for &args in args {
+        for &input in &[HELLO, &data[..], &noise[..], b""] {
+            let file = match super::run_lzop(args, input) {
+                Some(file) => file,
+                None => return,
+            };
+            assert_eq!(input, &read_all(&file).unwrap()[..], "lzop {:?}", args);
+        }
+    }
+}
+
+#[test]
+fn test_reader_checksum_mismatch() {
+    for &file in &[HELLO_ADLER32, HELLO_CRC32] {
+        // header, compressed data and stored data
+        for &i in &[40, 70, file.len() - 5] {
+            let mut file = file.to_vec();
+            file[i] ^= 1;
+            assert_eq!(io::ErrorKind::InvalidData, read_all(&file).unwrap_err().kind());
+        }
+    }
+}
+
+#[test]
+fn test_reader_truncated() {
+    let err = read_all(&HELLO_ADLER32[..HELLO_ADLER32.len() - 2]).unwrap_err();
+    assert_eq!(io::ErrorKind::UnexpectedEof, err.kind());
+    let err = read_all(b"\x89LZO\0\r\n\x1a").unwrap_err();
+    assert_eq!(io::ErrorKind::UnexpectedEof, err.kind());
+    let err = read_all(b"LZO").unwrap_err();
+    assert_eq!(io::ErrorKind::UnexpectedEof, err.kind());
+    let err = read_all(b"not an lzop file").unwrap_err();
+    assert_eq!(io::ErrorKind::InvalidData, err.kind());
+}
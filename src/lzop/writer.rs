@@ -0,0 +1,296 @@
+use std::cmp;
+use std::io::{self, Write};
+
+use checksum::{adler32, crc32};
+use stream::{self, BlockWrite};
+use {Compressor, compress_bound};
+use super::*;
+
+/// The block size lzop uses, 256 KiB.
+pub const DEFAULT_BLOCK_SIZE: usize = 256 * 1024;
+
+/// The level recorded in the header. lzop compresses with LZO1X-1 for levels
+/// 2 to 6 and defaults to 3, so this is what `lzop` writes for the same data.
+const LEVEL: u8 = 3;
+
+/// The LZO version recorded in the header, 2.10.
+/// lzop only records it; a fixed value keeps the output the same whatever library is linked.
+const LIB_VERSION: u16 = 0x20a0;
+
+const CHECKSUM_FLAGS: u32 = F_ADLER32_D | F_ADLER32_C | F_CRC32_D | F_CRC32_C | F_H_CRC32;
+
+/// The header fields and checksums of a file written by `Writer`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Options {
+    /// The name of the original file, at most 255 bytes. Empty if there is none.
+    pub filename: Vec<u8>,
+    /// The Unix mode of the original file.
+    pub mode: u32,
+    /// The modification time of the original file in seconds since the epoch.
+    pub mtime: u64,
+    /// Which checksums to write, any of `F_ADLER32_D`, `F_ADLER32_C`,
+    /// `F_CRC32_D`, `F_CRC32_C` and `F_H_CRC32`.
+    ///
+    /// The default is `F_ADLER32_D`, as lzop does.
+    pub checksums: u32,
+    /// The size of the blocks the data is split into, at most `MAX_BLOCK_SIZE`.
+    pub block_size: usize,
+}
+
+impl Default for Options {
+    fn default() -> Options {
+        Options {
+            filename: Vec::new(),
+            mode: 0,
+            mtime: 0,
+            checksums: F_ADLER32_D,
+            block_size: DEFAULT_BLOCK_SIZE,
+        }
+    }
+}
+
+/// A writer for `.lzo` files that `lzop -d` can read.
+///
+/// The header is written by `new`. Data is compressed with LZO1X-1 in blocks;
+/// blocks that do not compress are stored as they are.
+/// The end marker is written by `finish`, or on drop with errors ignored.
+///
+/// Example
+///
+/// ```rust
+/// use std::io::Write;
+///
+/// let mut writer = minilzo::lzop::Writer::new(Vec::new()).unwrap();
+/// writer.write_all(b"foobar").unwrap();
+/// let file = writer.finish().unwrap();
+/// ```
+pub struct Writer<W: Write> {
+    inner: Option<W>,
+    compressor: Compressor,
+    flags: u32,
+    block_size: usize,
+    buf: Vec<u8>,
+    out: Vec<u8>,
+}
+
+fn invalid_input(msg: &str) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidInput, msg)
+}
+
+impl<W: Write> Writer<W> {
+    /// Write the header of a file with the default `Options` to `inner`.
+    pub fn new(inner: W) -> io::Result<Writer<W>> {
+        Writer::with_options(inner, &Options::default())
+    }
+
+    /// Write the header of a file with the given `Options` to `inner`.
+    ///
+    /// Invalid options return `InvalidInput` before anything is written.
+    pub fn with_options(mut inner: W, options: &Options) -> io::Result<Writer<W>> {
+        if options.filename.len() > 255 {
+            return Err(invalid_input("lzop filename longer than 255 bytes"))
+        }
+        if options.checksums & !CHECKSUM_FLAGS != 0 {
+            return Err(invalid_input("invalid lzop checksum flags"))
+        }
+        if options.block_size == 0 || options.block_size > MAX_BLOCK_SIZE {
+            return Err(invalid_input("invalid lzop block size"))
+        }
+
+        let flags = F_OS_UNIX | options.checksums;
+
+        let mut header = Vec::with_capacity(34 + options.filename.len());
+        header.extend_from_slice(&VERSION.to_be_bytes());
+        header.extend_from_slice(&LIB_VERSION.to_be_bytes());
+        header.extend_from_slice(&0x0940u16.to_be_bytes());
+        header.push(M_LZO1X_1);
+        header.push(LEVEL);
+        header.extend_from_slice(&flags.to_be_bytes());
+        header.extend_from_slice(&options.mode.to_be_bytes());
+        header.extend_from_slice(&(options.mtime as u32).to_be_bytes());
+        header.extend_from_slice(&((options.mtime >> 32) as u32).to_be_bytes());
+        header.push(options.filename.len() as u8);
+        header.extend_from_slice(&options.filename);
+        let checksum = if flags & F_H_CRC32 != 0 {
+            crc32(&header)
+        } else {
+            adler32(&header)
+        };
+        header.extend_from_slice(&checksum.to_be_bytes());
+
+        inner.write_all(&MAGIC)?;
+        inner.write_all(&header)?;
+
+        Ok(Writer {
+            inner: Some(inner),
+            compressor: Compressor::new(),
+            flags,
+            block_size: options.block_size,
+            buf: Vec::with_capacity(options.block_size),
+            out: Vec::new(),
+        })
+    }
+
+    /// A reference to the underlying writer.
+    pub fn get_ref(&self) -> &W {
+        self.inner.as_ref().unwrap()
+    }
+
+    /// A mutable reference to the underlying writer.
+    ///
+    /// Writing to it directly corrupts the file.
+    pub fn get_mut(&mut self) -> &mut W {
+        self.inner.as_mut().unwrap()
+    }
+
+    /// Write the remaining data and the end marker, and return the underlying writer.
+    pub fn finish(mut self) -> io::Result<W> {
+        stream::finish(&mut self)
+    }
+}
+
+impl<W: Write> BlockWrite for Writer<W> {
+    type Inner = W;
+
+    fn inner(&mut self) -> &mut Option<W> {
+        &mut self.inner
+    }
+
+    fn write_block(&mut self) -> io::Result<()> {
+        if self.buf.is_empty() {
+            return Ok(())
+        }
+
+        let len = self.buf.len();
+        let flags = self.flags;
+        let mut header = Vec::with_capacity(24);
+        header.extend_from_slice(&(len as u32).to_be_bytes());
+
+        self.out.clear();
+        self.out.reserve(compress_bound(len));
+        let clen = self.compressor.compress_vec_append(&self.buf, &mut self.out)?;
+        let stored = clen >= len;
+        let data = if stored { &self.buf } else { &self.out };
+        header.extend_from_slice(&(data.len() as u32).to_be_bytes());
+
+        if flags & F_ADLER32_D != 0 {
+            header.extend_from_slice(&adler32(&self.buf).to_be_bytes());
+        }
+        if flags & F_CRC32_D != 0 {
+            header.extend_from_slice(&crc32(&self.buf).to_be_bytes());
+        }
+        if !stored {
+            if flags & F_ADLER32_C != 0 {
+                header.extend_from_slice(&adler32(&self.out).to_be_bytes());
+            }
+            if flags & F_CRC32_C != 0 {
+                header.extend_from_slice(&crc32(&self.out).to_be_bytes());
+            }
+        }
+
+        let inner = self.inner.as_mut().unwrap();
+        inner.write_all(&header)?;
+        inner.write_all(data)?;
+        self.buf.clear();
+        Ok(())
+    }
+
+    fn write_end(&mut self) -> io::Result<()> {
+        self.write_block()?;
+        self.inner.as_mut().unwrap().write_all(&[0; 4])
+    }
+}
+
+impl<W: Write> Write for Writer<W> {
+    fn write(&mut self, data: &[u8]) -> io::Result<usize> {
+        if self.buf.len() == self.block_size {
+            self.write_block()?;
+        }
+        let n = cmp::min(data.len(), self.block_size - self.buf.len());
+        self.buf.extend_from_slice(&data[..n]);
+        Ok(n)
+    }
+
+    /// Write the buffered data as a possibly short block and flush the underlying writer.
+    fn flush(&mut self) -> io::Result<()> {
+        stream::flush(self)
+    }
+}
+
+impl<W: Write> Drop for Writer<W> {
+    fn drop(&mut self) {
+        stream::drop(self);
+    }
+}
+
+#[cfg(test)]
+fn hello_options(checksums: u32) -> Options {
+    Options {
+        filename: b"hello.txt".to_vec(),
+        mode: 0o100644,
+        mtime: 1500000000,
+        checksums,
+        block_size: 60,
+    }
+}
+
+// Only pins the output, `test_writer_lzop` checks that lzop reads it.
+#[test]
+fn test_writer_output() {
+    use super::reader::{HELLO, HELLO_ADLER32, HELLO_CRC32};
+
+    let mut writer = Writer::with_options(Vec::new(), &hello_options(F_ADLER32_D | F_ADLER32_C)).unwrap();
+    writer.write_all(HELLO).unwrap();
+    assert_eq!(HELLO_ADLER32, &writer.finish().unwrap()[..]);
+
+    let mut writer = Writer::with_options(Vec::new(), &hello_options(F_CRC32_D | F_CRC32_C | F_H_CRC32)).unwrap();
+    writer.write_all(HELLO).unwrap();
+    assert_eq!(HELLO_CRC32, &writer.finish().unwrap()[..]);
+}
+
+#[test]
+fn test_writer_lzop() {
+    let data = ::test_numbers(600_000, 997);
+    let noise = ::test_noise(100_000);
+    let checksums = [F_ADLER32_D, F_ADLER32_D | F_ADLER32_C, F_CRC32_D | F_CRC32_C | F_H_CRC32, 0];
+    for &checksums in &checksums {
+        let options = Options { checksums, block_size: DEFAULT_BLOCK_SIZE, ..hello_options(0) };
+        for &input in &[super::reader::HELLO, &data[..], &noise[..], b""] {
+            let mut writer = Writer::with_options(Vec::new(), &options).unwrap();
+            writer.write_all(input).unwrap();
+            let file = writer.finish().unwrap();
+
+            if super::run_lzop(&["-t"], &file).is_none() {
+                return
+            }
+            assert_eq!(input, &super::run_lzop(&["-dc"], &file).unwrap()[..], "checksums {:#x}", checksums);
+        }
+    }
+}
+
+#[test]
+fn test_writer_round() {
+    use std::io::Read;
+
+    let data = ::test_numbers(100_000, 997);
+
+    let mut writer = Writer::new(Vec::new()).unwrap();
+    writer.write_all(&data).unwrap();
+    let file = writer.finish().unwrap();
+
+    let mut reader = Reader::new(&file[..]).unwrap();
+    assert_eq!(F_OS_UNIX | F_ADLER32_D, reader.header().flags);
+    assert!(reader.header().filename.is_empty());
+    let mut out = Vec::new();
+    reader.read_to_end(&mut out).unwrap();
+    assert_eq!(data, out);
+}
+
+#[test]
+fn test_writer_invalid_options() {
+    let options = Options { filename: vec![b'a'; 256], ..Options::default() };
+    assert_eq!(io::ErrorKind::InvalidInput, Writer::with_options(Vec::new(), &options).err().unwrap().kind());
+
+    let options = Options { checksums: F_H_FILTER, ..Options::default() };
+    assert_eq!(io::ErrorKind::InvalidInput, Writer::with_options(Vec::new(), &options).err().unwrap().kind());
+}
@@ -0,0 +1,363 @@
+//! LZO1X-1 compression in Rust.
+//!
+//! This is a port of `lzo1x_1_compress` (`lzo1x_c.ch`) as built for 64-bit
+//! little-endian targets with unaligned access, so the output is byte-identical
+//! to the C library there. On 32-bit targets the C library and the kernel extend
+//! matches 4 bytes at a time and stop at a different point near the end of the
+//! input, so their output can differ. The LZO1X-1(11), (12) and (15) variants only differ in the number
+//! of dictionary bits.
+//!
+//! With `rle` set it follows `lzorle1x_1_compress` of the Linux kernel instead,
+//! which encodes runs of zeros as a special M4 instruction.
+
+use std::cmp;
+use std::mem::MaybeUninit;
+
+const M2_MAX_LEN: usize = 8;
+const M3_MAX_LEN: usize = 33;
+const M4_MAX_LEN: usize = 9;
+const M2_MAX_OFFSET: usize = 0x0800;
+const M3_MAX_OFFSET: usize = 0x4000;
+const M4_MAX_OFFSET: usize = 0xbfff;
+const M3_MARKER: u8 = 32;
+const M4_MARKER: u8 = 16;
+
+/// Input is compressed in chunks of this size, so dictionary offsets fit into 16 bits.
+const CHUNK_LEN: usize = M4_MAX_OFFSET + 1;
+/// LZO-RLE cannot encode the largest M4 offset, it would look like a run of zeros.
+const RLE_CHUNK_LEN: usize = M4_MAX_OFFSET;
+
+const MIN_ZERO_RUN_LENGTH: usize = 4;
+const MAX_ZERO_RUN_LENGTH: usize = 2047 + MIN_ZERO_RUN_LENGTH;
+
+/// The version byte of the LZO-RLE bitstream.
+pub const RLE_VERSION: u8 = 1;
+/// The number of dictionary bits the kernel uses.
+pub const RLE_DICT_BITS: u32 = 13;
+
+struct Encoder<'a> {
+    output: &'a mut [MaybeUninit<u8>],
+    op: usize,
+    /// How far back the byte holding the literal count of the last instruction is.
+    state_offset: usize,
+    /// Whether to write LZO-RLE.
+    rle: bool,
+}
+
+impl<'a> Encoder<'a> {
+    #[inline]
+    fn push(&mut self, b: u8) {
+        self.output[self.op] = MaybeUninit::new(b);
+        self.op += 1;
+    }
+
+    #[inline]
+    fn push_slice(&mut self, s: &[u8]) {
+        for (o, &i) in self.output[self.op..self.op + s.len()].iter_mut().zip(s) {
+            *o = MaybeUninit::new(i);
+        }
+        self.op += s.len();
+    }
+
+    /// Merge a short literal run into the last two bits of the previous instruction.
+    #[inline]
+    fn or_state(&mut self, t: usize) {
+        let i = self.op - self.state_offset;
+        let b = unsafe { self.output[i].assume_init() };
+        self.output[i] = MaybeUninit::new(b | t as u8);
+    }
+
+    /// Encode a length that does not fit into the instruction byte.
+    #[inline]
+    fn push_run(&mut self, mut len: usize) {
+        while len > 255 {
+            len -= 255;
+            self.push(0);
+        }
+        self.push(len as u8);
+    }
+
+    fn literals(&mut self, lit: &[u8]) {
+        let t = lit.len();
+        if t <= 3 {
+            self.or_state(t);
+        } else if t <= 18 {
+            self.push((t - 3) as u8);
+        } else {
+            self.push(0);
+            self.push_run(t - 18);
+        }
+        self.push_slice(lit);
+    }
+
+    /// Encode a run of zeros as LZO-RLE does.
+    fn zero_run(&mut self, len: usize) {
+        let r = (len - MIN_ZERO_RUN_LENGTH) as u32;
+        self.push_slice(&((r << 21) | 0xfffc18 | (r & 7)).to_le_bytes());
+        self.state_offset = 3;
+    }
+}
+
+#[inline]
+fn le32(b: &[u8], i: usize) -> u32 {
+    u32::from(b[i]) | u32::from(b[i + 1]) << 8 | u32::from(b[i + 2]) << 16 | u32::from(b[i + 3]) << 24
+}
+
+#[inline]
+fn le64(b: &[u8], i: usize) -> u64 {
+    u64::from(le32(b, i)) | u64::from(le32(b, i + 4)) << 32
+}
+
+/// Compress one chunk starting at `start`.
+///
+/// `ti` is the number of literals still pending from the previous chunk.
+/// Returns the number of literals pending after this chunk.
+fn compress_chunk(enc: &mut Encoder, input: &[u8], start: usize, len: usize,
+                  mut ti: usize, dict: &mut [u16], bits: u32) -> usize {
+    let rle = enc.rle;
+    let in_end = start + len;
+    let ip_end = in_end - 20;
+    let mask = (1 << bits) - 1;
+
+    let mut ip = start;
+    let mut ii = start;
+    if ti < 4 {
+        ip += 4 - ti;
+    }
+
+    // The C code enters its loop at the `literal` label and jumps to `next`,
+    // skipping the step, only after a match.
+    let mut literal = true;
+    loop {
+        let mut m_pos = 0;
+        let mut run = 0;
+        loop {
+            if literal {
+                ip += 1 + ((ip - ii) >> 5);
+            }
+            literal = true;
+            if ip >= ip_end {
+                return in_end - (ii - ti);
+            }
+            let dv = le32(input, ip);
+            if rle && dv == 0 {
+                let limit = cmp::min(ip_end, ip + MAX_ZERO_RUN_LENGTH + 1);
+                let mut ir = ip + 4;
+                while ir < limit && input[ir] == 0 {
+                    ir += 1;
+                }
+                run = cmp::min(ir - ip, MAX_ZERO_RUN_LENGTH);
+                break;
+            }
+            let dindex = (dv.wrapping_mul(0x1824429d) >> (32 - bits)) as usize & mask;
+            let pos = start + dict[dindex] as usize;
+            dict[dindex] = (ip - start) as u16;
+            if dv == le32(input, pos) {
+                m_pos = pos;
+                break;
+            }
+        }
+
+        // a match or a run of zeros
+        ii -= ti;
+        ti = 0;
+        if ip != ii {
+            enc.literals(&input[ii..ip]);
+        }
+        literal = false;
+
+        if run > 0 {
+            ip += run;
+            ii = ip;
+            enc.zero_run(run);
+            continue;
+        }
+
+        let mut m_len = 4;
+        let mut v = le64(input, ip + m_len) ^ le64(input, m_pos + m_len);
+        if v == 0 {
+            loop {
+                m_len += 8;
+                v = le64(input, ip + m_len) ^ le64(input, m_pos + m_len);
+                if ip + m_len >= ip_end {
+                    break;
+                }
+                if v != 0 {
+                    m_len += v.trailing_zeros() as usize / 8;
+                    break;
+                }
+            }
+        } else {
+            m_len += v.trailing_zeros() as usize / 8;
+        }
+
+        let mut m_off = ip - m_pos;
+        ip += m_len;
+        if m_len <= M2_MAX_LEN && m_off <= M2_MAX_OFFSET {
+            m_off -= 1;
+            enc.push((((m_len - 1) << 5) | ((m_off & 7) << 2)) as u8);
+            enc.push((m_off >> 3) as u8);
+        } else if m_off <= M3_MAX_OFFSET {
+            m_off -= 1;
+            if m_len <= M3_MAX_LEN {
+                enc.push(M3_MARKER | (m_len - 2) as u8);
+            } else {
+                enc.push(M3_MARKER);
+                enc.push_run(m_len - M3_MAX_LEN);
+            }
+            enc.push((m_off << 2) as u8);
+            enc.push((m_off >> 6) as u8);
+        } else {
+            m_off -= 0x4000;
+            if m_len <= M4_MAX_LEN {
+                enc.push(M4_MARKER | ((m_off >> 11) & 8) as u8 | (m_len - 2) as u8);
+            } else {
+                if rle && m_off & 0x403f == 0x403f && (261..=264).contains(&m_len) {
+                    // These would read as a run of zeros once literals are merged in.
+                    ip -= m_len - 260;
+                    m_len = 260;
+                }
+                enc.push(M4_MARKER | ((m_off >> 11) & 8) as u8);
+                enc.push_run(m_len - M4_MAX_LEN);
+            }
+            enc.push((m_off << 2) as u8);
+            enc.push((m_off >> 6) as u8);
+        }
+        enc.state_offset = 2;
+        ii = ip;
+    }
+}
+
+/// Number of dictionary entries a compressor with `bits` dictionary bits needs.
+pub fn dict_len(bits: u32) -> usize {
+    1 << bits
+}
+
+/// Compress `input` into `output` with a dictionary of `bits` bits.
+///
+/// `output` must hold at least `compress_bound(input.len())` bytes and `dict`
+/// at least `dict_len(bits)` entries. Returns the number of bytes written.
+#[cfg_attr(not(feature = "pure-rust"), allow(dead_code))]
+pub fn compress(input: &[u8], output: &mut [MaybeUninit<u8>], dict: &mut [u16], bits: u32) -> usize {
+    compress_impl(input, output, dict, bits, false)
+}
+
+/// Compress `input` into `output` in the LZO-RLE format of the Linux kernel.
+///
+/// `output` must hold at least `compress_bound(input.len()) + 2` bytes and
+/// `dict` at least `dict_len(RLE_DICT_BITS)` entries.
+pub fn compress_rle(input: &[u8], output: &mut [MaybeUninit<u8>], dict: &mut [u16]) -> usize {
+    compress_impl(input, output, dict, RLE_DICT_BITS, true)
+}
+
+fn compress_impl(input: &[u8], output: &mut [MaybeUninit<u8>], dict: &mut [u16], bits: u32, rle: bool) -> usize {
+    let dict = &mut dict[..dict_len(bits)];
+    let mut enc = Encoder { output, op: 0, state_offset: 2, rle };
+
+    let chunk_len = if rle {
+        // LZO1X never starts with 17 unless the input is empty, so it marks the version.
+        enc.push(17);
+        enc.push(RLE_VERSION);
+        RLE_CHUNK_LEN
+    } else {
+        CHUNK_LEN
+    };
+    let data_start = enc.op;
+
+    let mut ip = 0;
+    let mut l = input.len();
+    let mut t = 0;
+    while l > 20 {
+        let ll = cmp::min(l, chunk_len);
+        for d in dict.iter_mut() {
+            *d = 0;
+        }
+        t = compress_chunk(&mut enc, input, ip, ll, t, dict, bits);
+        ip += ll;
+        l -= ll;
+    }
+    t += l;
+
+    if t > 0 {
+        let lit = &input[input.len() - t..];
+        if enc.op == data_start && t <= 238 {
+            enc.push(17 + t as u8);
+            enc.push_slice(lit);
+        } else {
+            enc.literals(lit);
+        }
+    }
+
+    enc.push(M4_MARKER | 1);
+    enc.push(0);
+    enc.push(0);
+
+    enc.op
+}
+
+#[cfg(test)]
+fn compress_vec(input: &[u8], bits: u32) -> Vec<u8> {
+    let mut out = vec![0; ::compress_bound(input.len())];
+    let mut dict = vec![0; dict_len(bits)];
+    let n = {
+        let out = unsafe { &mut *(&mut out[..] as *mut [u8] as *mut [MaybeUninit<u8>]) };
+        compress(input, out, &mut dict, bits)
+    };
+    out.truncate(n);
+    out
+}
+
+#[test]
+fn test_compress_lorem() {
+    use super::decompress::{LOREM, LOREM_COMPRESSED};
+
+    assert_eq!(&LOREM_COMPRESSED[..], &compress_vec(LOREM.as_bytes(), 14)[..]);
+}
+
+#[test]
+fn test_compress_short() {
+    assert_eq!(vec![20, b'f', b'o', b'o', 17, 0, 0], compress_vec(b"foo", 14));
+    assert_eq!(vec![17, 0, 0], compress_vec(b"", 14));
+}
+
+#[test]
+fn test_compress_zeros() {
+    assert_eq!(593, compress_vec(&[0; 128*1024], 14).len());
+}
+
+#[test]
+fn test_compress_round_chunks() {
+    // Text-like data spanning several chunks, with some incompressible noise.
+    let mut data = Vec::new();
+    let mut seed: u32 = 1;
+    while data.len() < 200 * 1024 {
+        seed = seed.wrapping_mul(1103515245).wrapping_add(12345);
+        if seed >> 28 == 0 {
+            data.extend((0..64).map(|i| (seed >> (i % 24)) as u8));
+        } else {
+            data.extend_from_slice(&super::decompress::LOREM.as_bytes()[(seed >> 24) as usize..]);
+        }
+    }
+
+    for &bits in &[11, 12, 14, 15] {
+        let compressed = compress_vec(&data, bits);
+        let mut out = vec![MaybeUninit::uninit(); data.len()];
+        assert_eq!(Ok(data.len()), super::decompress::decompress(&compressed, &mut out));
+        let out: Vec<u8> = out.iter().map(|b| unsafe { b.assume_init() }).collect();
+        assert_eq!(data, out);
+    }
+}
+
+#[test]
+fn test_compress_rle_zeros() {
+    let mut out = vec![MaybeUninit::uninit(); ::compress_bound(4096) + 2];
+    let mut dict = vec![0; dict_len(RLE_DICT_BITS)];
+    let n = compress_rle(&[0; 4096], &mut out, &mut dict);
+    let out: Vec<u8> = out[..n].iter().map(|b| unsafe { b.assume_init() }).collect();
+
+    // version, 5 literals, runs of 2051 and 2020 zeros, 20 literals, end of stream
+    let mut expected = vec![17, 1, 2, 0, 0, 0, 0, 0, 0x1f, 0xfc, 0xff, 0xff, 0x18, 0xfc, 0xff, 0xfc, 0, 2];
+    expected.extend_from_slice(&[0; 20]);
+    expected.extend_from_slice(&[17, 0, 0]);
+    assert_eq!(expected, out);
+}
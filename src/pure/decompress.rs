@@ -0,0 +1,401 @@
+//! LZO1X decompression in Rust.
+//!
+//! This is a port of `lzo1x_decompress_safe` (`lzo1x_d.ch` compiled with
+//! `LZO_TEST_OVERRUN`). Every bound check happens at the same point as in the
+//! C code, so malformed input fails with the same error.
+//!
+//! `decompress_rle` follows `lzo1x_decompress_safe` of the Linux kernel
+//! instead, which also reads the LZO-RLE bitstream.
+
+use std::mem::MaybeUninit;
+
+use {DecompressError, Error};
+
+const M2_MAX_OFFSET: usize = 0x0800;
+const MIN_ZERO_RUN_LENGTH: usize = 4;
+
+/// Where the decoder continues after a step, mirroring the labels of `lzo1x_d.ch`.
+enum State {
+    Literal,
+    FirstLiteralRun,
+    Match,
+    MatchDone,
+    MatchNext,
+}
+
+struct Decoder<'a, 'b> {
+    input: &'a [u8],
+    output: &'b mut [MaybeUninit<u8>],
+    ip: usize,
+    op: usize,
+    /// Decode runs of zeros of the LZO-RLE bitstream.
+    rle: bool,
+    /// Apply the additional checks of the kernel decompressor.
+    kernel: bool,
+}
+
+impl<'a, 'b> Decoder<'a, 'b> {
+    #[inline]
+    fn need_ip(&self, n: usize) -> Result<(), Error> {
+        if self.input.len() - self.ip < n {
+            return Err(Error::InputOverrun)
+        }
+        Ok(())
+    }
+
+    #[inline]
+    fn need_op(&self, n: usize) -> Result<(), Error> {
+        if self.output.len() - self.op < n {
+            return Err(Error::OutputOverrun)
+        }
+        Ok(())
+    }
+
+    /// Read the next input byte.
+    ///
+    /// The C code reads these without a check, relying on an earlier `NEED_IP`.
+    /// We check anyway so that a bug here cannot turn into a panic.
+    #[inline]
+    fn next(&mut self) -> Result<u8, Error> {
+        match self.input.get(self.ip) {
+            Some(&b) => {
+                self.ip += 1;
+                Ok(b)
+            }
+            None => Err(Error::InputOverrun),
+        }
+    }
+
+    #[inline]
+    fn peek(&self) -> Result<u8, Error> {
+        self.input.get(self.ip).cloned().ok_or(Error::InputOverrun)
+    }
+
+    #[inline]
+    fn next_le16(&mut self) -> Result<usize, Error> {
+        let lo = self.next()? as usize;
+        let hi = self.next()? as usize;
+        Ok(lo | (hi << 8))
+    }
+
+    /// Extend a length of zero by a run of zero bytes, as in `lzo1x_d.ch`.
+    #[inline]
+    fn run_length(&mut self, mut t: usize, base: usize, overrun: Error) -> Result<usize, Error> {
+        while self.peek()? == 0 {
+            t += 255;
+            self.ip += 1;
+            if t > usize::MAX - 511 {
+                return Err(overrun)
+            }
+            self.need_ip(1)?;
+        }
+        Ok(t + base + self.next()? as usize)
+    }
+
+    #[inline]
+    fn copy_literals(&mut self, n: usize) {
+        for (o, &i) in self.output[self.op..self.op + n].iter_mut().zip(&self.input[self.ip..self.ip + n]) {
+            *o = MaybeUninit::new(i);
+        }
+        self.ip += n;
+        self.op += n;
+    }
+
+    /// Check that a match `dist` bytes back starts inside the output written so far.
+    #[inline]
+    fn test_lb(&self, dist: usize) -> Result<(), Error> {
+        if dist == 0 || dist > self.op {
+            return Err(Error::LookbehindOverrun)
+        }
+        Ok(())
+    }
+
+    /// Copy `n` bytes starting `dist` bytes back; the ranges may overlap.
+    #[inline]
+    fn copy_match(&mut self, dist: usize, n: usize) {
+        let start = self.op - dist;
+        for i in 0..n {
+            self.output[self.op + i] = self.output[start + i];
+        }
+        self.op += n;
+    }
+
+    fn run(&mut self) -> Result<(), Error> {
+        let mut t: usize = 0;
+        let mut state = State::Literal;
+
+        self.need_ip(1)?;
+        if self.input[self.ip] > 17 {
+            t = self.next()? as usize - 17;
+            if t < 4 {
+                state = State::MatchNext;
+            } else {
+                self.need_op(t)?;
+                self.need_ip(t + 3)?;
+                self.copy_literals(t);
+                state = State::FirstLiteralRun;
+            }
+        }
+
+        loop {
+            match state {
+                State::Literal => {
+                    self.need_ip(3)?;
+                    t = self.next()? as usize;
+                    if t >= 16 {
+                        state = State::Match;
+                        continue;
+                    }
+                    if t == 0 {
+                        t = self.run_length(t, 15, Error::InputOverrun)?;
+                    }
+                    self.need_op(t + 3)?;
+                    self.need_ip(t + 6)?;
+                    self.copy_literals(t + 3);
+                    state = State::FirstLiteralRun;
+                }
+                State::FirstLiteralRun => {
+                    t = self.next()? as usize;
+                    if t >= 16 {
+                        state = State::Match;
+                        continue;
+                    }
+                    let dist = 1 + M2_MAX_OFFSET + (t >> 2) + ((self.next()? as usize) << 2);
+                    self.test_lb(dist)?;
+                    self.need_op(3)?;
+                    self.copy_match(dist, 3);
+                    state = State::MatchDone;
+                }
+                State::Match => {
+                    let dist;
+                    if t >= 64 {
+                        // M2 match
+                        dist = 1 + ((t >> 2) & 7) + ((self.next()? as usize) << 3);
+                        t = (t >> 5) - 1;
+                    } else if t >= 32 {
+                        // M3 match
+                        t &= 31;
+                        if t == 0 {
+                            t = self.run_length(t, 31, Error::OutputOverrun)?;
+                            self.need_ip(2)?;
+                        }
+                        dist = 1 + (self.next_le16()? >> 2);
+                    } else if t >= 16 {
+                        if self.rle && t & 0xf8 == 0x18 {
+                            self.need_ip(2)?;
+                            if self.input[self.ip] & 0xfc == 0xfc && self.input[self.ip + 1] == 0xff {
+                                // run of zeros
+                                self.need_ip(3)?;
+                                let len = ((t & 7) | (self.input[self.ip + 2] as usize) << 3) + MIN_ZERO_RUN_LENGTH;
+                                self.need_op(len)?;
+                                for o in &mut self.output[self.op..self.op + len] {
+                                    *o = MaybeUninit::new(0);
+                                }
+                                self.op += len;
+                                t = (self.input[self.ip] & 3) as usize;
+                                self.ip += 3;
+                                state = if t == 0 { State::Literal } else { State::MatchNext };
+                                continue;
+                            }
+                        }
+
+                        // M4 match
+                        let high = (t & 8) << 11;
+                        t &= 7;
+                        if t == 0 {
+                            t = self.run_length(t, 7, Error::OutputOverrun)?;
+                            self.need_ip(2)?;
+                        }
+                        let low = self.next_le16()? >> 2;
+                        if high + low == 0 {
+                            if self.kernel && t != 1 {
+                                return Err(Error::Error)
+                            }
+                            return self.eof();
+                        }
+                        dist = high + low + 0x4000;
+                    } else {
+                        // M1 match
+                        let dist = 1 + (t >> 2) + ((self.next()? as usize) << 2);
+                        self.test_lb(dist)?;
+                        self.need_op(2)?;
+                        self.copy_match(dist, 2);
+                        state = State::MatchDone;
+                        continue;
+                    }
+
+                    self.test_lb(dist)?;
+                    self.need_op(t + 2)?;
+                    self.copy_match(dist, t + 2);
+                    state = State::MatchDone;
+                }
+                State::MatchDone => {
+                    t = (self.input[self.ip - 2] & 3) as usize;
+                    state = if t == 0 { State::Literal } else { State::MatchNext };
+                }
+                State::MatchNext => {
+                    self.need_op(t)?;
+                    self.need_ip(t + 3)?;
+                    self.copy_literals(t);
+                    t = self.next()? as usize;
+                    state = State::Match;
+                }
+            }
+        }
+    }
+
+    fn eof(&self) -> Result<(), Error> {
+        if self.ip == self.input.len() {
+            Ok(())
+        } else if self.ip < self.input.len() {
+            Err(Error::InputNotConsumed)
+        } else {
+            Err(Error::InputOverrun)
+        }
+    }
+}
+
+/// Decompress LZO1X data from `input` into `output`.
+///
+/// Returns the number of bytes written to `output`.
+/// On failure the error records how far the decoder got in both buffers.
+#[cfg_attr(not(feature = "pure-rust"), allow(dead_code))]
+pub fn decompress(input: &[u8], output: &mut [MaybeUninit<u8>]) -> Result<usize, DecompressError> {
+    decode(Decoder { input, output, ip: 0, op: 0, rle: false, kernel: false })
+}
+
+/// The version byte at the start of `input`, if it has one.
+///
+/// LZO1X never starts with 17 unless it is empty, so a stream starting with
+/// 17 and long enough to hold more than the end of stream carries a version.
+pub fn rle_version(input: &[u8]) -> Option<u8> {
+    if input.len() >= 5 && input[0] == 17 {
+        return Some(input[1])
+    }
+    None
+}
+
+/// Decompress LZO1X or LZO-RLE data from `input` into `output`, as the Linux kernel does.
+///
+/// Returns the number of bytes written to `output`.
+pub fn decompress_rle(input: &[u8], output: &mut [MaybeUninit<u8>]) -> Result<usize, DecompressError> {
+    if input.len() < 3 {
+        return Err(DecompressError { error: Error::InputOverrun, input_offset: Some(0), output_len: 0 })
+    }
+    let version = rle_version(input);
+    decode(Decoder {
+        input,
+        output,
+        ip: if version.is_some() { 2 } else { 0 },
+        op: 0,
+        rle: version.unwrap_or(0) > 0,
+        kernel: true,
+    })
+}
+
+fn decode(mut decoder: Decoder) -> Result<usize, DecompressError> {
+    match decoder.run() {
+        Ok(()) => Ok(decoder.op),
+        Err(error) => Err(DecompressError { error, input_offset: Some(decoder.ip), output_len: decoder.op }),
+    }
+}
+
+#[cfg(test)]
+pub const LOREM: &str = "Lorem ipsum dolor sit amet, consetetur sadipscing elitr, sed diam nonumy eirmod \
+                     tempor invidunt ut labore et dolore magna aliquyam erat, sed diam voluptua. At \
+                     vero eos et accusam et justo duo dolores et ea rebum. Stet clita kasd gubergren, \
+                     no sea takimata sanctus est Lorem ipsum dolor sit amet. Lorem ipsum dolor sit \
+                     amet, consetetur sadipscing elitr, sed diam nonumy eirmod tempor invidunt ut \
+                     labore et dolore magna aliquyam erat, sed diam voluptua.";
+
+// The output of lzo1x_1_compress for LOREM.
+#[cfg(test)]
+pub const LOREM_COMPRESSED: [u8; 290] = [0, 91, 76, 111, 114, 101, 109, 32, 105, 112, 115, 117, 109, 32, 100, 111, 108, 111, 114, 32, 115, 105, 116, 32, 97, 109, 101, 116, 44, 32, 99, 111, 110, 115, 101, 116, 101, 116, 117, 114, 32, 115, 97, 100, 105, 112, 115, 99, 105, 110, 103, 32, 101, 108, 105, 116, 114, 44, 32, 115, 101, 100, 32, 100, 105, 97, 109, 32, 110, 111, 110, 117, 109, 121, 32, 101, 105, 114, 109, 111, 100, 32, 116, 101, 109, 112, 111, 114, 32, 105, 110, 118, 105, 100, 117, 110, 116, 32, 117, 116, 32, 108, 97, 98, 111, 114, 101, 32, 101, 116, 32, 128, 12, 0, 4, 101, 32, 109, 97, 103, 110, 97, 32, 97, 108, 105, 113, 117, 121, 97, 109, 32, 101, 114, 97, 116, 44, 40, 60, 1, 0, 3, 118, 111, 108, 117, 112, 116, 117, 97, 46, 32, 65, 116, 32, 118, 101, 114, 111, 32, 101, 111, 115, 116, 7, 2, 97, 99, 99, 117, 115, 124, 5, 8, 116, 32, 106, 117, 115, 116, 111, 32, 100, 117, 111, 173, 22, 101, 156, 3, 0, 52, 101, 97, 32, 114, 101, 98, 117, 109, 46, 32, 83, 116, 101, 116, 32, 99, 108, 105, 116, 97, 32, 107, 97, 115, 100, 32, 103, 117, 98, 101, 114, 103, 114, 101, 110, 44, 32, 110, 111, 32, 115, 101, 97, 32, 116, 97, 107, 105, 109, 97, 116, 97, 32, 115, 97, 110, 99, 116, 117, 115, 32, 101, 115, 116, 32, 76, 111, 114, 101, 109, 51, 45, 4, 46, 57, 108, 0, 32, 83, 156, 4, 10, 105, 97, 109, 32, 118, 111, 108, 117, 112, 116, 117, 97, 46, 17, 0, 0];
+
+#[cfg(test)]
+fn decompress_vec(input: &[u8], len: usize) -> Result<Vec<u8>, Error> {
+    let mut out = vec![0; len];
+    let n = {
+        let out = unsafe { &mut *(&mut out[..] as *mut [u8] as *mut [MaybeUninit<u8>]) };
+        decompress(input, out).map_err(|e| e.error)?
+    };
+    out.truncate(n);
+    Ok(out)
+}
+
+#[test]
+fn test_decompress_lorem() {
+    assert_eq!(LOREM.as_bytes(), &decompress_vec(&LOREM_COMPRESSED, LOREM.len()).unwrap()[..]);
+    assert_eq!(LOREM.as_bytes(), &decompress_vec(&LOREM_COMPRESSED, 1024).unwrap()[..]);
+}
+
+#[test]
+fn test_decompress_output_overrun() {
+    assert_eq!(Err(Error::OutputOverrun), decompress_vec(&LOREM_COMPRESSED, 100));
+    assert_eq!(Err(Error::OutputOverrun), decompress_vec(&LOREM_COMPRESSED, LOREM.len() - 1));
+}
+
+#[test]
+fn test_decompress_input_overrun() {
+    assert_eq!(Err(Error::InputOverrun), decompress_vec(&[], 10));
+    assert_eq!(Err(Error::InputOverrun), decompress_vec(&LOREM_COMPRESSED[..LOREM_COMPRESSED.len() - 1], 1024));
+    assert_eq!(Err(Error::InputOverrun), decompress_vec(&LOREM_COMPRESSED[..200], 1024));
+}
+
+#[test]
+fn test_decompress_eof_only() {
+    assert_eq!(Ok(vec![]), decompress_vec(&[0x11, 0, 0], 10));
+    assert_eq!(Err(Error::InputNotConsumed), decompress_vec(&[0x11, 0, 0, 0], 10));
+}
+
+#[test]
+fn test_decompress_lookbehind_overrun() {
+    assert_eq!(Err(Error::LookbehindOverrun),
+               decompress_vec(&[0x15, b'a', b'b', b'c', b'd', 0xfc, 0xff, 0x11], 100));
+}
+
+#[test]
+fn test_decompress_error_offset() {
+    let input = [0x15, b'a', b'b', b'c', b'd', 0xfc, 0xff, 0x11];
+    let mut out = vec![MaybeUninit::uninit(); 100];
+    let err = decompress(&input, &mut out).unwrap_err();
+    assert_eq!(DecompressError { error: Error::LookbehindOverrun, input_offset: Some(7), output_len: 4 }, err);
+}
+
+#[test]
+fn test_decompress_rle() {
+    let mut input = vec![17, 1, 2, b'a', b'b', b'c', b'd', b'e', 0x1f, 0xfd, 0xff, 0xff, b'f'];
+    input.extend_from_slice(&[0x18, 0xfc, 0xff, 0, 17, 0, 0]);
+    let mut out = vec![MaybeUninit::uninit(); 5000];
+    let n = decompress_rle(&input, &mut out).unwrap();
+    let out: Vec<u8> = out[..n].iter().map(|b| unsafe { b.assume_init() }).collect();
+
+    let mut expected = b"abcde".to_vec();
+    expected.extend_from_slice(&[0; 2051]);
+    expected.push(b'f');
+    expected.extend_from_slice(&[0; 4]);
+    assert_eq!(expected, out);
+
+    // Version 0 reads the same bytes as an M4 match.
+    input[1] = 0;
+    let mut out = vec![MaybeUninit::uninit(); 5000];
+    assert_eq!(Error::LookbehindOverrun, decompress_rle(&input, &mut out).unwrap_err().error);
+}
+
+#[test]
+fn test_decompress_rle_plain_lzo() {
+    let mut out = vec![MaybeUninit::uninit(); LOREM.len()];
+    assert_eq!(Ok(LOREM.len()), decompress_rle(&LOREM_COMPRESSED, &mut out));
+    assert_eq!(None, rle_version(&LOREM_COMPRESSED));
+    assert_eq!(None, rle_version(&[17, 0, 0]));
+    assert_eq!(Some(1), rle_version(&[17, 1, 17, 0, 0]));
+}
+
+#[test]
+fn test_decompress_rle_eof() {
+    let mut out = vec![MaybeUninit::uninit(); 10];
+    let input = [0x15, b'a', b'b', b'c', b'd', 0x12, 0, 0];
+    assert_eq!(Ok(4), decompress(&input, &mut out));
+    assert_eq!(Error::Error, decompress_rle(&input, &mut out).unwrap_err().error);
+    assert_eq!(Error::InputOverrun, decompress_rle(&[0x11, 0], &mut out).unwrap_err().error);
+}
@@ -0,0 +1,12 @@
+//! LZO1X implemented in Rust, used with the `pure-rust` feature
+//! and for LZO-RLE, which the C library does not support.
+
+mod compress;
+mod decompress;
+
+#[cfg(feature = "pure-rust")]
+pub use self::compress::compress;
+pub use self::compress::{compress_rle, dict_len, RLE_DICT_BITS};
+#[cfg(feature = "pure-rust")]
+pub use self::decompress::decompress;
+pub use self::decompress::{decompress_rle, rle_version};
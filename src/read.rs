@@ -0,0 +1,205 @@
+//! Streaming decompression from a `Read`.
+//!
+//! `Decoder` reads the framed format written by `write::Encoder`.
+
+use std::io::{self, BufRead, Read};
+
+use decompress_into_with_context;
+use stream::{self, BlockBuf, BlockRead, invalid_data, read_be32, read_full};
+use write::{MAGIC, MAX_BLOCK_SIZE};
+
+/// A decompressor that reads the framed format from an underlying reader.
+///
+/// Concatenated frames are read as one stream.
+///
+/// Example
+///
+/// ```rust
+/// use std::io::{Read, Write};
+///
+/// let mut encoder = minilzo::write::Encoder::new(Vec::new());
+/// encoder.write_all(b"foobar").unwrap();
+/// let framed = encoder.finish().unwrap();
+///
+/// let mut decoder = minilzo::read::Decoder::new(&framed[..]);
+/// let mut data = Vec::new();
+/// decoder.read_to_end(&mut data).unwrap();
+/// assert_eq!(b"foobar", &data[..]);
+/// ```
+pub struct Decoder<R: Read> {
+    inner: R,
+    max_block_size: usize,
+    in_frame: bool,
+    input: Vec<u8>,
+    buf: BlockBuf,
+}
+
+impl<R: Read> Decoder<R> {
+    /// Create a decoder that accepts blocks of up to `write::MAX_BLOCK_SIZE` bytes.
+    pub fn new(inner: R) -> Decoder<R> {
+        Decoder::with_max_block_size(inner, MAX_BLOCK_SIZE)
+    }
+
+    /// Create a decoder that accepts blocks of up to `max_block_size` bytes.
+    ///
+    /// A block header announcing a larger block fails with `InvalidData`
+    /// before anything is allocated for it.
+    pub fn with_max_block_size(inner: R, max_block_size: usize) -> Decoder<R> {
+        Decoder {
+            inner,
+            max_block_size,
+            in_frame: false,
+            input: Vec::new(),
+            buf: BlockBuf::new(),
+        }
+    }
+
+    /// A reference to the underlying reader.
+    pub fn get_ref(&self) -> &R {
+        &self.inner
+    }
+
+    /// A mutable reference to the underlying reader.
+    pub fn get_mut(&mut self) -> &mut R {
+        &mut self.inner
+    }
+
+    /// Return the underlying reader.
+    ///
+    /// Data that was already read from it but not yet returned is lost.
+    pub fn into_inner(self) -> R {
+        self.inner
+    }
+}
+
+impl<R: Read> BlockRead for Decoder<R> {
+    fn block_buf(&mut self) -> &mut BlockBuf {
+        &mut self.buf
+    }
+
+    fn next_block(&mut self) -> io::Result<bool> {
+        loop {
+            if !self.in_frame {
+                let mut magic = [0; 4];
+                match read_full(&mut self.inner, &mut magic)? {
+                    0 => return Ok(false),
+                    4 => {}
+                    _ => return Err(io::Error::new(io::ErrorKind::UnexpectedEof, "truncated frame header")),
+                }
+                if magic != MAGIC {
+                    return Err(invalid_data("invalid frame magic"))
+                }
+                self.in_frame = true;
+            }
+
+            let len = read_be32(&mut self.inner)? as usize;
+            if len == 0 {
+                self.in_frame = false;
+                continue;
+            }
+            let clen = read_be32(&mut self.inner)? as usize;
+            if len > self.max_block_size {
+                return Err(invalid_data("block larger than the maximum block size"))
+            }
+            if clen > len {
+                return Err(invalid_data("compressed block larger than its data"))
+            }
+
+            let buf = self.buf.clear();
+            if clen == len {
+                buf.resize(len, 0);
+                self.inner.read_exact(buf)?;
+                return Ok(true)
+            }
+
+            self.input.resize(clen, 0);
+            self.inner.read_exact(&mut self.input)?;
+            buf.reserve(len);
+            let n = decompress_into_with_context(&self.input, &mut buf.spare_capacity_mut()[..len])?;
+            unsafe { buf.set_len(n) };
+            if n != len {
+                return Err(invalid_data("block shorter than its header says"))
+            }
+            return Ok(true)
+        }
+    }
+}
+
+impl<R: Read> Read for Decoder<R> {
+    fn read(&mut self, out: &mut [u8]) -> io::Result<usize> {
+        stream::read(self, out)
+    }
+}
+
+impl<R: Read> BufRead for Decoder<R> {
+    fn fill_buf(&mut self) -> io::Result<&[u8]> {
+        stream::fill_buf(self)
+    }
+
+    fn consume(&mut self, amt: usize) {
+        self.buf.consume(amt);
+    }
+}
+
+#[cfg(test)]
+fn encode(data: &[u8], block_size: usize) -> Vec<u8> {
+    use std::io::Write;
+
+    let mut encoder = ::write::Encoder::with_block_size(Vec::new(), block_size);
+    encoder.write_all(data).unwrap();
+    encoder.finish().unwrap()
+}
+
+#[test]
+fn test_decoder_round() {
+    let data = ::test_numbers(100_000, 997);
+    let framed = encode(&data, 64 * 1024);
+
+    let mut out = Vec::new();
+    Decoder::new(&framed[..]).read_to_end(&mut out).unwrap();
+    assert_eq!(data, out);
+}
+
+#[test]
+fn test_decoder_concatenated() {
+    let mut framed = encode(b"foo", 16);
+    framed.extend(encode(&[0; 1000], 256));
+    framed.extend(encode(b"", 16));
+
+    let mut decoder = Decoder::new(&framed[..]);
+    let mut line = String::new();
+    decoder.read_line(&mut line).unwrap();
+    assert_eq!("foo\0\0", &line[..5]);
+
+    let mut out = Vec::new();
+    decoder.read_to_end(&mut out).unwrap();
+    assert_eq!(1003, line.len() + out.len());
+}
+
+#[test]
+fn test_decoder_max_block_size() {
+    let framed = encode(&[0; 1000], 1000);
+
+    let mut out = Vec::new();
+    let err = Decoder::with_max_block_size(&framed[..], 999).read_to_end(&mut out).unwrap_err();
+    assert_eq!(io::ErrorKind::InvalidData, err.kind());
+
+    let corrupt = b"LZOF\xff\xff\xff\xff\0\0\0\x10";
+    let err = Decoder::new(&corrupt[..]).read_to_end(&mut out).unwrap_err();
+    assert_eq!(io::ErrorKind::InvalidData, err.kind());
+}
+
+#[test]
+fn test_decoder_truncated() {
+    let framed = encode(&[0; 1000], 1000);
+
+    let mut out = Vec::new();
+    let err = Decoder::new(&framed[..framed.len() - 6]).read_to_end(&mut out).unwrap_err();
+    assert_eq!(io::ErrorKind::UnexpectedEof, err.kind());
+
+    let err = Decoder::new(&b"LZ"[..]).read_to_end(&mut out).unwrap_err();
+    assert_eq!(io::ErrorKind::UnexpectedEof, err.kind());
+
+    let err = Decoder::new(&b"ABCD"[..]).read_to_end(&mut out).unwrap_err();
+    assert_eq!(io::ErrorKind::InvalidData, err.kind());
+}
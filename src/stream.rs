@@ -0,0 +1,188 @@
+//! Helpers shared by the streaming readers and writers.
+
+use std::cmp;
+use std::io::{self, BufRead, Read, Write};
+
+pub fn invalid_data(msg: &str) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, msg)
+}
+
+/// Read as much of `buf` as possible, returning less only at the end of the input.
+pub fn read_full<R: Read>(r: &mut R, buf: &mut [u8]) -> io::Result<usize> {
+    let mut n = 0;
+    while n < buf.len() {
+        match r.read(&mut buf[n..]) {
+            Ok(0) => break,
+            Ok(m) => n += m,
+            Err(ref e) if e.kind() == io::ErrorKind::Interrupted => {}
+            Err(e) => return Err(e),
+        }
+    }
+    Ok(n)
+}
+
+pub fn read_be32<R: Read>(r: &mut R) -> io::Result<u32> {
+    let mut b = [0; 4];
+    r.read_exact(&mut b)?;
+    Ok(u32::from_be_bytes(b))
+}
+
+/// The decompressed data of the current block and how much of it was read.
+pub struct BlockBuf {
+    pub data: Vec<u8>,
+    pub pos: usize,
+    pub done: bool,
+}
+
+impl BlockBuf {
+    pub fn new() -> BlockBuf {
+        BlockBuf {
+            data: Vec::new(),
+            pos: 0,
+            done: false,
+        }
+    }
+
+    /// Drop the current block and return the emptied buffer for the next one.
+    pub fn clear(&mut self) -> &mut Vec<u8> {
+        self.data.clear();
+        self.pos = 0;
+        &mut self.data
+    }
+
+    pub fn consume(&mut self, amt: usize) {
+        self.pos = cmp::min(self.pos + amt, self.data.len());
+    }
+}
+
+/// A reader that decompresses its input one block at a time into a `BlockBuf`.
+pub trait BlockRead {
+    fn block_buf(&mut self) -> &mut BlockBuf;
+
+    /// Decompress the next block into `block_buf`. Returns `false` at the end of the input.
+    fn next_block(&mut self) -> io::Result<bool>;
+}
+
+/// `BufRead::fill_buf` for a `BlockRead`.
+pub fn fill_buf<B: BlockRead>(r: &mut B) -> io::Result<&[u8]> {
+    loop {
+        let b = r.block_buf();
+        if b.pos < b.data.len() || b.done {
+            break;
+        }
+        if !r.next_block()? {
+            r.block_buf().done = true;
+        }
+    }
+    let b = r.block_buf();
+    Ok(&b.data[b.pos..])
+}
+
+/// `Read::read` for a `BufRead`.
+pub fn read<R: BufRead>(r: &mut R, out: &mut [u8]) -> io::Result<usize> {
+    let n = {
+        let data = r.fill_buf()?;
+        let n = cmp::min(data.len(), out.len());
+        out[..n].copy_from_slice(&data[..n]);
+        n
+    };
+    r.consume(n);
+    Ok(n)
+}
+
+/// A writer that collects its input and compresses it one block at a time
+/// into the underlying writer, which is `None` once `finish` took it.
+pub trait BlockWrite {
+    type Inner: Write;
+
+    fn inner(&mut self) -> &mut Option<Self::Inner>;
+
+    /// Compress and write the buffered data, if there is any.
+    fn write_block(&mut self) -> io::Result<()>;
+
+    /// Write the buffered data and whatever ends the stream.
+    fn write_end(&mut self) -> io::Result<()> {
+        self.write_block()
+    }
+}
+
+/// `finish` of a `BlockWrite`: write the end and return the underlying writer.
+pub fn finish<B: BlockWrite>(w: &mut B) -> io::Result<B::Inner> {
+    let result = w.write_end();
+    // Taken even on failure, so that drop does not write the end again.
+    let inner = w.inner().take().unwrap();
+    result.map(|()| inner)
+}
+
+/// `Write::flush` for a `BlockWrite`.
+pub fn flush<B: BlockWrite>(w: &mut B) -> io::Result<()> {
+    w.write_block()?;
+    w.inner().as_mut().unwrap().flush()
+}
+
+/// `Drop::drop` for a `BlockWrite`: write the end unless `finish` did, ignoring errors.
+pub fn drop<B: BlockWrite>(w: &mut B) {
+    if w.inner().is_some() {
+        let _ = w.write_end();
+    }
+}
+
+// Writes each block as it is and "end" at the end.
+#[cfg(test)]
+struct Blocks<W: Write> {
+    inner: Option<W>,
+    buf: Vec<u8>,
+}
+
+#[cfg(test)]
+impl<W: Write> BlockWrite for Blocks<W> {
+    type Inner = W;
+
+    fn inner(&mut self) -> &mut Option<W> {
+        &mut self.inner
+    }
+
+    fn write_block(&mut self) -> io::Result<()> {
+        if !self.buf.is_empty() {
+            self.inner.as_mut().unwrap().write_all(&self.buf)?;
+            self.buf.clear();
+        }
+        Ok(())
+    }
+
+    fn write_end(&mut self) -> io::Result<()> {
+        self.write_block()?;
+        self.inner.as_mut().unwrap().write_all(b"end")
+    }
+}
+
+#[cfg(test)]
+impl<W: Write> Drop for Blocks<W> {
+    fn drop(&mut self) {
+        drop(self);
+    }
+}
+
+#[test]
+fn test_block_write() {
+    let mut blocks = Blocks { inner: Some(Vec::new()), buf: b"foo".to_vec() };
+    flush(&mut blocks).unwrap();
+    blocks.buf.extend_from_slice(b"bar");
+    assert_eq!(b"foobarend", &finish(&mut blocks).unwrap()[..]);
+
+    let mut out = Vec::new();
+    ::std::mem::drop(Blocks { inner: Some(&mut out), buf: b"foo".to_vec() });
+    assert_eq!(b"fooend", &out[..]);
+}
+
+#[test]
+fn test_block_write_finish_error() {
+    // The block is written, the end fails.
+    let mut failing = ::FailingWriter { ok_writes: 1, writes: 0 };
+    {
+        let mut blocks = Blocks { inner: Some(&mut failing), buf: b"foo".to_vec() };
+        assert!(finish(&mut blocks).is_err());
+    }
+    // Nothing is written again on drop.
+    assert_eq!(2, failing.writes);
+}
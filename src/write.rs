@@ -0,0 +1,203 @@
+//! Streaming compression into a `Write`.
+//!
+//! The data is split into blocks that are compressed independently and
+//! written in a simple framed format:
+//!
+//! - the 4 byte magic `MAGIC`,
+//! - any number of blocks, each a big-endian `u32` uncompressed length,
+//!   a big-endian `u32` compressed length and the compressed data.
+//!   If both lengths are equal the block did not compress and is stored as it is,
+//! - an uncompressed length of 0 that ends the frame.
+//!
+//! Several frames can be concatenated; `read::Decoder` reads them as one stream.
+
+use std::cmp;
+use std::io::{self, Write};
+
+use stream::{self, BlockWrite};
+use {Compressor, compress_bound};
+
+/// The magic bytes at the start of every frame.
+pub const MAGIC: [u8; 4] = *b"LZOF";
+
+/// The default block size of an `Encoder`, 256 KiB.
+pub const DEFAULT_BLOCK_SIZE: usize = 256 * 1024;
+
+/// The largest block size an `Encoder` accepts, 64 MiB.
+pub const MAX_BLOCK_SIZE: usize = 64 * 1024 * 1024;
+
+/// A compressor that writes the framed format to an underlying writer.
+///
+/// The end of the frame is written by `finish`, or on drop with errors ignored.
+///
+/// Example
+///
+/// ```rust
+/// use std::io::Write;
+///
+/// let mut encoder = minilzo::write::Encoder::new(Vec::new());
+/// encoder.write_all(b"foobar").unwrap();
+/// let framed = encoder.finish().unwrap();
+/// ```
+pub struct Encoder<W: Write> {
+    inner: Option<W>,
+    compressor: Compressor,
+    block_size: usize,
+    buf: Vec<u8>,
+    out: Vec<u8>,
+    header_written: bool,
+}
+
+impl<W: Write> Encoder<W> {
+    /// Create an encoder with the default block size of 256 KiB.
+    pub fn new(inner: W) -> Encoder<W> {
+        Encoder::with_block_size(inner, DEFAULT_BLOCK_SIZE)
+    }
+
+    /// Create an encoder that compresses blocks of `block_size` bytes.
+    ///
+    /// Panics if `block_size` is 0 or larger than `MAX_BLOCK_SIZE`.
+    pub fn with_block_size(inner: W, block_size: usize) -> Encoder<W> {
+        assert!(block_size > 0 && block_size <= MAX_BLOCK_SIZE, "invalid block size {}", block_size);
+        Encoder {
+            inner: Some(inner),
+            compressor: Compressor::new(),
+            block_size,
+            buf: Vec::with_capacity(block_size),
+            out: Vec::new(),
+            header_written: false,
+        }
+    }
+
+    /// The block size of this encoder.
+    pub fn block_size(&self) -> usize {
+        self.block_size
+    }
+
+    /// A reference to the underlying writer.
+    pub fn get_ref(&self) -> &W {
+        self.inner.as_ref().unwrap()
+    }
+
+    /// A mutable reference to the underlying writer.
+    ///
+    /// Writing to it directly corrupts the frame.
+    pub fn get_mut(&mut self) -> &mut W {
+        self.inner.as_mut().unwrap()
+    }
+
+    /// Write the remaining data and the end of the frame, and return the underlying writer.
+    pub fn finish(mut self) -> io::Result<W> {
+        stream::finish(&mut self)
+    }
+
+    fn write_header(&mut self) -> io::Result<()> {
+        if !self.header_written {
+            self.inner.as_mut().unwrap().write_all(&MAGIC)?;
+            self.header_written = true;
+        }
+        Ok(())
+    }
+}
+
+impl<W: Write> BlockWrite for Encoder<W> {
+    type Inner = W;
+
+    fn inner(&mut self) -> &mut Option<W> {
+        &mut self.inner
+    }
+
+    fn write_block(&mut self) -> io::Result<()> {
+        if self.buf.is_empty() {
+            return Ok(())
+        }
+        self.write_header()?;
+
+        let len = self.buf.len();
+        self.out.clear();
+        self.out.reserve(8 + compress_bound(len));
+        self.out.extend_from_slice(&(len as u32).to_be_bytes());
+        self.out.extend_from_slice(&[0; 4]);
+        let mut outlen = self.compressor.compress_vec_append(&self.buf, &mut self.out)?;
+        if outlen >= len {
+            self.out.truncate(8);
+            self.out.extend_from_slice(&self.buf);
+            outlen = len;
+        }
+        self.out[4..8].copy_from_slice(&(outlen as u32).to_be_bytes());
+
+        self.inner.as_mut().unwrap().write_all(&self.out)?;
+        self.buf.clear();
+        Ok(())
+    }
+
+    fn write_end(&mut self) -> io::Result<()> {
+        self.write_block()?;
+        self.write_header()?;
+        self.inner.as_mut().unwrap().write_all(&[0; 4])
+    }
+}
+
+impl<W: Write> Write for Encoder<W> {
+    fn write(&mut self, data: &[u8]) -> io::Result<usize> {
+        if self.buf.len() == self.block_size {
+            self.write_block()?;
+        }
+        let n = cmp::min(data.len(), self.block_size - self.buf.len());
+        self.buf.extend_from_slice(&data[..n]);
+        Ok(n)
+    }
+
+    /// Write the buffered data as a possibly short block and flush the underlying writer.
+    fn flush(&mut self) -> io::Result<()> {
+        stream::flush(self)
+    }
+}
+
+impl<W: Write> Drop for Encoder<W> {
+    fn drop(&mut self) {
+        stream::drop(self);
+    }
+}
+
+#[test]
+fn test_encoder_empty() {
+    let framed = Encoder::new(Vec::new()).finish().unwrap();
+    assert_eq!(b"LZOF\0\0\0\0", &framed[..]);
+}
+
+#[test]
+fn test_encoder_blocks() {
+    let data = [0; 100 * 1024];
+    let mut encoder = Encoder::with_block_size(Vec::new(), 64 * 1024);
+    encoder.write_all(&data).unwrap();
+    let framed = encoder.finish().unwrap();
+
+    assert_eq!(&MAGIC, &framed[..4]);
+    assert_eq!(&(64 * 1024u32).to_be_bytes(), &framed[4..8]);
+    let clen = u32::from_be_bytes([framed[8], framed[9], framed[10], framed[11]]) as usize;
+    let block = &framed[12..12 + clen];
+    assert_eq!(&data[..64 * 1024], &::decompress(block, 64 * 1024).unwrap()[..]);
+
+    let rest = &framed[12 + clen..];
+    assert_eq!(&(36 * 1024u32).to_be_bytes(), &rest[..4]);
+    assert_eq!(&[0; 4], &rest[rest.len() - 4..]);
+}
+
+#[test]
+fn test_encoder_stores_incompressible() {
+    let mut encoder = Encoder::new(Vec::new());
+    encoder.write_all(b"foo").unwrap();
+    encoder.flush().unwrap();
+    assert_eq!(b"LZOF\0\0\0\x03\0\0\0\x03foo", &encoder.get_ref()[..]);
+}
+
+#[test]
+fn test_encoder_finish_on_drop() {
+    let mut framed = Vec::new();
+    {
+        let mut encoder = Encoder::new(&mut framed);
+        encoder.write_all(b"foo").unwrap();
+    }
+    assert_eq!(b"LZOF\0\0\0\x03\0\0\0\x03foo\0\0\0\0", &framed[..]);
+}